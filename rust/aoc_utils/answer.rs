@@ -0,0 +1,200 @@
+//! What a part answers with. Most puzzles want a number, of whatever width
+//! the day happens to compute in; a few want a word or a picture of letters
+//! drawn in `#` and `.`.
+//!
+//! Integers compare by value whichever variant holds them, so an answer
+//! computed as `u64` matches one recorded as text.
+
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    /// An integer too wide for 64 bits, as a sign and magnitude so the full
+    /// range of both `i128` and `u128` fits.
+    Big {
+        negative: bool,
+        magnitude: u128,
+    },
+    Text(String),
+    /// A multi-line picture, rows separated by `\n`.
+    Art(String),
+}
+
+impl Answer {
+    pub fn art(picture: impl Into<String>) -> Answer {
+        Answer::Art(picture.into().trim_end_matches('\n').to_string())
+    }
+
+    /// Reads an answer back from the text it was printed or recorded as,
+    /// taking the narrowest variant which holds it.
+    pub fn parse(s: &str) -> Answer {
+        if let Ok(n) = s.parse::<i64>() {
+            return Answer::Signed(n);
+        }
+
+        if let Ok(n) = s.parse::<u64>() {
+            return Answer::Unsigned(n);
+        }
+
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s),
+        };
+
+        if let Ok(magnitude) = digits.parse::<u128>() {
+            return Answer::Big {
+                negative,
+                magnitude,
+            };
+        }
+
+        if s.trim_end().contains('\n') {
+            Answer::art(s)
+        } else {
+            Answer::Text(s.to_string())
+        }
+    }
+
+    /// The sign and magnitude of an integer answer, with zero never
+    /// negative.
+    fn integer(&self) -> Option<(bool, u128)> {
+        match *self {
+            Answer::Signed(n) => Some((n < 0, n.unsigned_abs() as u128)),
+            Answer::Unsigned(n) => Some((false, n as u128)),
+            Answer::Big {
+                negative,
+                magnitude,
+            } => Some((negative && magnitude != 0, magnitude)),
+            Answer::Text(_) | Answer::Art(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    /// Pictures ignore trailing whitespace on their rows, which is easily
+    /// lost when one is recorded.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Art(a), Answer::Art(b)) => a
+                .lines()
+                .map(str::trim_end)
+                .eq(b.lines().map(str::trim_end)),
+            _ => matches!((self.integer(), other.integer()), (Some(a), Some(b)) if a == b),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Big { .. } => {
+                let (negative, magnitude) = self.integer().unwrap();
+                write!(f, "{}{}", if negative { "-" } else { "" }, magnitude)
+            }
+            Answer::Text(s) | Answer::Art(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Unsigned(n.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::Unsigned(n)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Unsigned(n as u64)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        Answer::Big {
+            negative: false,
+            magnitude: n,
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::Signed(n.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Signed(n)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        Answer::Big {
+            negative: n < 0,
+            magnitude: n.unsigned_abs(),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers_compare_by_value() {
+        assert_eq!(Answer::from(35_u32), Answer::from(35_i64));
+        assert_eq!(Answer::from(71503_u128), Answer::parse("71503"));
+        assert_eq!(Answer::from(-2_i128), Answer::from(-2_i64));
+        assert_eq!(Answer::from(0_i128), Answer::parse("-0"));
+        assert_ne!(Answer::from(35_u64), Answer::from(-35_i64));
+        assert_ne!(Answer::from(35_u64), Answer::from("thirty-five"));
+
+        assert_eq!(
+            Answer::parse("340282366920938463463374607431768211455"),
+            Answer::from(u128::MAX)
+        );
+        assert_eq!(Answer::from(i128::MIN).to_string(), i128::MIN.to_string());
+        assert_eq!(
+            Answer::parse(&i128::MIN.to_string()),
+            Answer::from(i128::MIN)
+        );
+    }
+
+    #[test]
+    fn test_text_and_art() {
+        assert_eq!(Answer::parse("PZGPKPEB"), Answer::from("PZGPKPEB"));
+
+        let art = Answer::art("#..#\n####\n#..#\n");
+        assert_eq!(art.to_string(), "#..#\n####\n#..#");
+        assert_eq!(art, Answer::parse("#..#  \n####\n#..#"));
+        assert_ne!(art, Answer::parse("#..#\n#..#\n#..#"));
+        assert_ne!(art, Answer::from("#..#\n####\n#..#"));
+    }
+}
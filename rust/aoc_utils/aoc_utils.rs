@@ -1,11 +1,12 @@
 use std::fs::File;
 use std::io::prelude::*;
+use std::io::Result;
 
-pub fn read_file(path: &str) -> Result<String, ()> {
-    let mut f = File::open(path).expect("FILE NOT FOUND");
+pub fn read_file(path: &str) -> Result<String> {
+    let mut f = File::open(path)?;
 
     let mut contents = String::new();
-    f.read_to_string(&mut contents).expect("CONTENT READ ERROR");
+    f.read_to_string(&mut contents)?;
 
     Ok(contents)
 }
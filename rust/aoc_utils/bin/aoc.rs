@@ -0,0 +1,288 @@
+//! Workspace-wide commands:
+//!
+//!     cargo run -p aoc_utils --bin aoc -- fetch <day> [--year <year>]
+//!     cargo run -p aoc_utils --bin aoc -- verify [--record] [dayNN...]
+//!     cargo run -p aoc_utils --bin aoc -- extract <day> <page.html> [--list] [--blocks 1,3]
+//!     cargo run -p aoc_utils --bin aoc -- new-day <day>
+//!
+//! `new-day` creates the crate of a day which does not exist yet, with an
+//! empty input, `answers.toml` and fixture directory, and adds it to the
+//! bench crate.
+//!
+//! `fetch` downloads a day's input through the cache of `aoc_utils::fetch`
+//! and puts it next to the day's `main.rs`, unless a non-empty one is
+//! already there.
+//!
+//! `extract` turns the examples of a saved puzzle page into fixtures under
+//! `tests/fixtures/dayNN`; `--list` shows the page's code blocks so others
+//! than the default can be picked with `--blocks`.
+//!
+//! `verify` runs every day which has an input through its `--json` runner
+//! and compares the answers with its `answers.toml`. `--record` writes the
+//! answers of parts which have none recorded yet.
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use aoc_utils::{
+    extract::Page,
+    fetch::Fetcher,
+    scaffold,
+    verify::{Answers, Verdict},
+};
+use serde_json::Value;
+
+fn workspace() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// The day crates of the workspace, in order.
+fn days(root: &Path) -> Vec<String> {
+    let mut days = std::fs::read_dir(root)
+        .unwrap()
+        .filter_map(Result::ok)
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| {
+            name.len() == 5
+                && name.starts_with("day")
+                && name[3..].bytes().all(|b| b.is_ascii_digit())
+        })
+        .collect::<Vec<_>>();
+
+    days.sort();
+    days
+}
+
+/// Whether `path` is an input, rather than missing or the empty one a new
+/// day starts with.
+fn has_input(path: &Path) -> bool {
+    path.metadata().is_ok_and(|m| m.len() > 0)
+}
+
+/// Runs `day` in release mode and returns its `--json` metrics.
+fn run_day(root: &Path, day: &str) -> Result<Value, String> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .args(["run", "--quiet", "--release", "-p", day, "--", "--json"])
+        .env("RUST_BACKTRACE", "0")
+        .current_dir(root)
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        // Prefer a panic's message over the notes printed after it.
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let lines = stderr.lines().collect::<Vec<_>>();
+        let reason = match lines.iter().position(|l| l.contains("panicked at")) {
+            Some(i) => lines.get(i + 1).copied(),
+            None => lines.iter().rfind(|l| !l.trim().is_empty()).copied(),
+        };
+
+        return Err(reason.unwrap_or("exited unsuccessfully").trim().to_string());
+    }
+
+    serde_json::from_slice(&output.stdout).map_err(|e| e.to_string())
+}
+
+/// Prints a verdict for both parts of each day and returns whether they all
+/// passed.
+fn verify(root: &Path, days: &[String], record: bool) -> bool {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in days {
+        let dir = root.join(day).join("src");
+
+        if !has_input(&dir.join("input")) {
+            println!("{}: no input, skipped", day);
+            continue;
+        }
+
+        let mut answers = match Answers::load(&dir) {
+            Ok(answers) => answers,
+            Err(e) => {
+                println!("{}: cannot read answers: {}", day, e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let metrics = run_day(root, day);
+        let mut recorded = false;
+
+        for part in [1, 2] {
+            let stage = metrics
+                .as_ref()
+                .map(|metrics| &metrics[format!("part{}", part)]);
+            let actual = match stage {
+                Ok(stage) => match (stage["answer"].as_str(), stage["error"].as_str()) {
+                    (Some(answer), _) => Ok(answer),
+                    (None, Some(e)) => Err(e),
+                    (None, None) => Err("no answer"),
+                },
+                Err(e) => Err(e.as_str()),
+            };
+
+            let verdict = Verdict::check(answers.get(part), actual);
+            println!("{} part {}: {}", day, part, verdict);
+
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Missing { actual } if record => {
+                    answers.set(part, actual);
+                    recorded = true;
+                    missing += 1;
+                }
+                Verdict::Missing { .. } => missing += 1,
+                Verdict::Fail { .. } | Verdict::Error(_) => failed += 1,
+            }
+        }
+
+        if recorded {
+            answers.save(&dir).unwrap();
+            println!("{}: recorded answers", day);
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+
+    failed == 0
+}
+
+fn fetch(root: &Path, day: u8, year: u16) -> Result<(), String> {
+    let dir = root.join(format!("day{:02}", day)).join("src");
+    let path = dir.join("input");
+
+    if has_input(&path) {
+        return Err(format!("{} already exists", path.display()));
+    }
+
+    if !dir.exists() {
+        return Err(format!("{} does not exist", dir.display()));
+    }
+
+    let fetcher = Fetcher::from_env().map_err(|e| e.to_string())?;
+    let input = fetcher.input(year, day).map_err(|e| e.to_string())?;
+
+    std::fs::write(&path, input).map_err(|e| e.to_string())?;
+    println!("wrote {}", path.display());
+
+    Ok(())
+}
+
+fn extract(root: &Path, day: u8, page: &str, options: &[&str]) -> Result<(), String> {
+    let html = std::fs::read_to_string(page).map_err(|e| format!("{}: {}", page, e))?;
+    let page = Page::parse(&html);
+
+    if options.contains(&"--list") {
+        for (i, block) in page.blocks.iter().enumerate() {
+            let first = block.text.lines().next().unwrap_or_default();
+            println!("{:>2} (part {}) {}", i + 1, block.part, first);
+        }
+
+        return Ok(());
+    }
+
+    let selection = match options.iter().position(|o| *o == "--blocks") {
+        Some(i) => options
+            .get(i + 1)
+            .ok_or("--blocks needs a list of block numbers")?
+            .split(',')
+            .map(|n| n.trim().parse::<usize>().map_err(|e| e.to_string()))
+            .collect::<Result<Vec<_>, _>>()?,
+        None => page.default_selection(),
+    };
+
+    let examples = page.examples(&selection);
+
+    if examples.is_empty() {
+        return Err("no examples found".to_string());
+    }
+
+    let dir = root.join("tests/fixtures").join(format!("day{:02}", day));
+
+    for path in aoc_utils::extract::write_fixtures(&dir, &examples).map_err(|e| e.to_string())? {
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn new_day(root: &Path, day: u8) -> Result<(), String> {
+    for path in scaffold::new_day(root, day).map_err(|e| e.to_string())? {
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn usage() -> ! {
+    eprintln!("usage: aoc fetch <day> [--year <year>]");
+    eprintln!("       aoc verify [--record] [dayNN...]");
+    eprintln!("       aoc extract <day> <page.html> [--list] [--blocks 1,3]");
+    eprintln!("       aoc new-day <day>");
+    std::process::exit(2);
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    let root = workspace();
+
+    match args.as_slice() {
+        ["fetch", day, rest @ ..] => {
+            let year = match rest {
+                [] => 2023,
+                ["--year", year] => year.parse().unwrap_or_else(|_| usage()),
+                _ => usage(),
+            };
+            let day = day.parse().unwrap_or_else(|_| usage());
+
+            if let Err(e) = fetch(&root, day, year) {
+                eprintln!("error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        ["extract", day, page, options @ ..] => {
+            let day = day.parse().unwrap_or_else(|_| usage());
+
+            if let Err(e) = extract(&root, day, page, options) {
+                eprintln!("error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        ["new-day", day] => {
+            let day = day.parse().unwrap_or_else(|_| usage());
+
+            if let Err(e) = new_day(&root, day) {
+                eprintln!("error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        ["verify", rest @ ..] => {
+            let record = rest.contains(&"--record");
+            let selected = rest
+                .iter()
+                .filter(|a| !a.starts_with("--"))
+                .map(|a| a.to_string())
+                .collect::<Vec<_>>();
+            let days = if selected.is_empty() {
+                days(&root)
+            } else {
+                selected
+            };
+
+            if !verify(&root, &days, record) {
+                std::process::exit(1);
+            }
+        }
+        _ => usage(),
+    }
+}
@@ -0,0 +1,246 @@
+//! Pulls the examples and their expected answers out of a saved puzzle page.
+//!
+//! Each part of a puzzle is an `<article>`; examples are its
+//! `<pre><code>` blocks and the answer is the last `<code><em>` in it. By
+//! default only the first block of each article is taken as an example,
+//! since later ones tend to be worked illustrations rather than inputs. Each
+//! part's answer goes to the closest example before it, so a part 2 without
+//! an example of its own reuses part 1's.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::verify::Answers;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Block {
+    /// The article, and so the part, the block appears in.
+    pub part: u8,
+    pub text: String,
+    offset: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Emphasis {
+    part: u8,
+    text: String,
+    offset: usize,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Page {
+    pub blocks: Vec<Block>,
+    answers: Vec<Emphasis>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Answers,
+}
+
+/// Replaces the entities puzzle pages use and drops any tags, such as the
+/// `<em>` highlighting inside examples.
+fn to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The contents between each `open` and the following `close` in `html`,
+/// with their offsets.
+fn between<'a>(html: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut found = Vec::new();
+    let mut rest = 0;
+
+    while let Some(start) = html[rest..].find(open) {
+        let start = rest + start + open.len();
+
+        match html[start..].find(close) {
+            Some(end) => {
+                found.push((start, &html[start..start + end]));
+                rest = start + end + close.len();
+            }
+            None => break,
+        }
+    }
+
+    found
+}
+
+impl Page {
+    pub fn parse(html: &str) -> Page {
+        let mut articles = between(html, "<article", "</article>");
+
+        if articles.is_empty() {
+            articles.push((0, html));
+        }
+
+        let mut page = Page::default();
+
+        for (part, (offset, article)) in (1..).zip(articles) {
+            // Blank out the blocks so highlighting inside them is not
+            // mistaken for an answer, keeping offsets intact.
+            let mut prose = article.to_string();
+
+            for (at, block) in between(article, "<pre><code>", "</code></pre>") {
+                page.blocks.push(Block {
+                    part,
+                    text: to_text(block),
+                    offset: offset + at,
+                });
+                prose.replace_range(at..at + block.len(), &" ".repeat(block.len()));
+            }
+
+            if let Some((at, answer)) = between(&prose, "<code><em>", "</em></code>").pop() {
+                page.answers.push(Emphasis {
+                    part,
+                    text: to_text(answer),
+                    offset: offset + at,
+                });
+            }
+        }
+
+        page
+    }
+
+    /// The first block of each article, as 1-based indices into `blocks`.
+    pub fn default_selection(&self) -> Vec<usize> {
+        let mut selection = Vec::new();
+
+        for (i, block) in self.blocks.iter().enumerate() {
+            if i == 0 || self.blocks[i - 1].part != block.part {
+                selection.push(i + 1);
+            }
+        }
+
+        selection
+    }
+
+    /// The examples made of the blocks at the 1-based indices in
+    /// `selection`, each with the answers which follow it.
+    pub fn examples(&self, selection: &[usize]) -> Vec<Example> {
+        let mut selected = selection
+            .iter()
+            .filter_map(|i| self.blocks.get(i.wrapping_sub(1)))
+            .collect::<Vec<_>>();
+        selected.sort_by_key(|block| block.offset);
+        selected.dedup_by_key(|block| block.offset);
+
+        let mut examples = selected
+            .iter()
+            .map(|block| Example {
+                input: block.text.clone(),
+                answers: Answers::default(),
+            })
+            .collect::<Vec<_>>();
+
+        for answer in &self.answers {
+            let owner = selected
+                .iter()
+                .rposition(|block| block.offset < answer.offset);
+
+            if let Some(i) = owner {
+                examples[i].answers.set(answer.part, answer.text.clone());
+            }
+        }
+
+        examples
+    }
+}
+
+/// Writes each example as `exampleN.txt` with its answers in `exampleN.toml`
+/// beside it, numbering after the fixtures already in `dir`.
+pub fn write_fixtures(dir: &Path, examples: &[Example]) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+
+    let mut written = Vec::new();
+    let mut n = 1;
+
+    for example in examples {
+        while dir.join(format!("example{}.txt", n)).exists() {
+            n += 1;
+        }
+
+        let path = dir.join(format!("example{}.txt", n));
+        fs::write(&path, &example.input)?;
+        fs::write(path.with_extension("toml"), example.answers.to_toml())?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 0: Test ---</h2>
+<p>For example:</p>
+<pre><code>1 &lt; 2
+<em>3</em> &amp; 4
+</code></pre>
+<p>Which looks like:</p>
+<pre><code>drawing
+</code></pre>
+<p>The answer is <code><em>7</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Now the total is <code><em>-12</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse_page() {
+        let page = Page::parse(PAGE);
+
+        assert_eq!(page.blocks.len(), 2);
+        assert_eq!(page.blocks[0].text, "1 < 2\n3 & 4\n");
+        assert_eq!(page.default_selection(), vec![1]);
+
+        assert_eq!(
+            page.examples(&page.default_selection()),
+            vec![Example {
+                input: "1 < 2\n3 & 4\n".to_string(),
+                answers: Answers {
+                    part1: Some("7".to_string()),
+                    part2: Some("-12".to_string()),
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        let page = Page::parse(&PAGE.replace(
+            "<p>Now the total",
+            "<pre><code><em>5</em> 6\n</code></pre><p>Now the total",
+        ));
+
+        assert_eq!(page.default_selection(), vec![1, 3]);
+
+        let examples = page.examples(&page.default_selection());
+        assert_eq!(examples[0].answers.get(1), Some("7"));
+        assert_eq!(examples[0].answers.get(2), None);
+        assert_eq!(examples[1].input, "5 6\n");
+        assert_eq!(examples[1].answers.get(2), Some("-12"));
+    }
+}
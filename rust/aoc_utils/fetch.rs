@@ -0,0 +1,342 @@
+//! Downloads puzzle inputs with a session cookie and keeps them in a cache
+//! directory, so each input is only ever requested once.
+//!
+//! Requests are spaced at least `min_interval` apart, even across separate
+//! runs, and the server's `429 Too Many Requests` is reported rather than
+//! retried.
+
+use std::{
+    fmt, fs, io,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = "github.com/CYBAI/aoc-2023 by cyb.ai.815@gmail.com";
+
+/// Where the time of the last request is kept, inside the cache directory.
+const LAST_REQUEST_FILE: &str = ".last-request";
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    Io(io::Error),
+    /// The server answered with something other than the input.
+    Http {
+        status: u16,
+        body: String,
+    },
+    RateLimited {
+        retry_after: Option<u64>,
+    },
+    /// The request never got an answer.
+    Transport(String),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(f, "no session cookie; set AOC_SESSION"),
+            FetchError::Io(e) => write!(f, "{}", e),
+            FetchError::Http { status, body } => {
+                write!(f, "server answered {}: {}", status, body.trim())
+            }
+            FetchError::RateLimited {
+                retry_after: Some(secs),
+            } => write!(f, "rate limited; retry after {} seconds", secs),
+            FetchError::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            FetchError::Transport(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        FetchError::Io(e)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    pub session: String,
+    pub cache_dir: PathBuf,
+    pub base_url: String,
+    pub min_interval: Duration,
+}
+
+impl Fetcher {
+    pub fn new(session: impl Into<String>, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            session: session.into(),
+            cache_dir: cache_dir.into(),
+            base_url: BASE_URL.to_string(),
+            min_interval: Duration::from_secs(5),
+        }
+    }
+
+    /// Reads the session from `AOC_SESSION` and the cache directory from
+    /// `AOC_CACHE_DIR`, defaulting to `$XDG_CACHE_HOME/aoc` or
+    /// `~/.cache/aoc`.
+    pub fn from_env() -> Result<Self, FetchError> {
+        let session = std::env::var("AOC_SESSION")
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .ok_or(FetchError::MissingSession)?;
+
+        let cache_dir = std::env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("XDG_CACHE_HOME").map(|d| PathBuf::from(d).join("aoc")))
+            .or_else(|| std::env::var_os("HOME").map(|d| PathBuf::from(d).join(".cache/aoc")))
+            .unwrap_or_else(|| PathBuf::from(".aoc-cache"));
+
+        Ok(Self::new(session.trim(), cache_dir))
+    }
+
+    pub fn cached_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// The input for `day`, from the cache when it has been downloaded
+    /// before.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let path = self.cached_path(year, day);
+
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+            Err(_) => {}
+        }
+
+        let input = self.download(year, day)?;
+
+        // Write through a temporary file so an interrupted run never leaves
+        // a truncated input behind to be trusted later.
+        fs::create_dir_all(path.parent().unwrap())?;
+        let partial = path.with_extension("part");
+        fs::write(&partial, &input)?;
+        fs::rename(&partial, &path)?;
+
+        Ok(input)
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        if self.session.is_empty() {
+            return Err(FetchError::MissingSession);
+        }
+
+        self.throttle()?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build()
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        match response {
+            Ok(response) => response.into_string().map_err(FetchError::Io),
+            Err(ureq::Error::Status(429, response)) => Err(FetchError::RateLimited {
+                retry_after: response.header("Retry-After").and_then(|s| s.parse().ok()),
+            }),
+            Err(ureq::Error::Status(status, response)) => Err(FetchError::Http {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(ureq::Error::Transport(e)) => Err(FetchError::Transport(e.to_string())),
+        }
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request made
+    /// with this cache directory, then records the new request.
+    fn throttle(&self) -> Result<(), FetchError> {
+        let stamp = self.cache_dir.join(LAST_REQUEST_FILE);
+        let now = || SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+
+        if let Some(wait) = last.and_then(|last| (last + self.min_interval).checked_sub(now())) {
+            thread::sleep(wait);
+        }
+
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(&stamp, now().as_millis().to_string())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        time::Instant,
+    };
+
+    /// A local stand-in for the puzzle server which answers each connection
+    /// with the next canned response and records the request lines and
+    /// cookies it was sent.
+    struct StandIn {
+        url: String,
+        requests: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl StandIn {
+        fn serve(responses: Vec<(u16, &'static str)>) -> StandIn {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let recorded = Arc::clone(&requests);
+
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut request_line = String::new();
+                    let mut cookie = String::new();
+                    reader.read_line(&mut request_line).unwrap();
+
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+
+                        if line.trim().is_empty() {
+                            break;
+                        }
+
+                        if let Some(value) = line.strip_prefix("Cookie: ") {
+                            cookie = value.trim().to_string();
+                        }
+                    }
+
+                    recorded
+                        .lock()
+                        .unwrap()
+                        .push((request_line.trim().to_string(), cookie));
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} Canned\r\nContent-Length: {}\r\nRetry-After: 60\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+
+            StandIn { url, requests }
+        }
+
+        fn requests(&self) -> Vec<(String, String)> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    fn temp_dir() -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let dir = std::env::temp_dir().join(format!(
+            "aoc-fetch-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn fetcher(server: &StandIn, min_interval: Duration) -> Fetcher {
+        Fetcher {
+            base_url: server.url.clone(),
+            min_interval,
+            ..Fetcher::new("secret", temp_dir())
+        }
+    }
+
+    #[test]
+    fn test_downloads_once() {
+        let server = StandIn::serve(vec![(200, "1 2 3\n")]);
+        let fetcher = fetcher(&server, Duration::ZERO);
+
+        assert_eq!(fetcher.input(2023, 9).unwrap(), "1 2 3\n");
+        assert_eq!(fetcher.input(2023, 9).unwrap(), "1 2 3\n");
+        assert_eq!(
+            fs::read_to_string(fetcher.cached_path(2023, 9)).unwrap(),
+            "1 2 3\n"
+        );
+        assert_eq!(
+            server.requests(),
+            vec![(
+                "GET /2023/day/9/input HTTP/1.1".to_string(),
+                "session=secret".to_string()
+            )]
+        );
+
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_errors_are_not_cached() {
+        let server = StandIn::serve(vec![
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (429, ""),
+        ]);
+        let fetcher = fetcher(&server, Duration::ZERO);
+
+        assert!(matches!(
+            fetcher.input(2023, 25),
+            Err(FetchError::Http { status: 404, .. })
+        ));
+        assert!(matches!(
+            fetcher.input(2023, 25),
+            Err(FetchError::RateLimited {
+                retry_after: Some(60)
+            })
+        ));
+        assert!(!fetcher.cached_path(2023, 25).exists());
+
+        let fetcher = Fetcher {
+            session: String::new(),
+            ..fetcher
+        };
+        assert!(matches!(
+            fetcher.input(2023, 1),
+            Err(FetchError::MissingSession)
+        ));
+
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_requests_are_spaced() {
+        let server = StandIn::serve(vec![(200, "a\n"), (200, "b\n")]);
+        let interval = Duration::from_millis(300);
+        let fetcher = fetcher(&server, interval);
+
+        let start = Instant::now();
+        fetcher.input(2023, 1).unwrap();
+        fetcher.input(2023, 2).unwrap();
+
+        assert!(start.elapsed() >= interval);
+        assert_eq!(server.requests().len(), 2);
+
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+}
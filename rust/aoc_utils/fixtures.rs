@@ -0,0 +1,224 @@
+//! Test cases kept as files rather than string literals.
+//!
+//! A fixture is `tests/fixtures/dayNN/<name>.txt` in the workspace with the
+//! expected answers in a sidecar `<name>.toml`, written like `answers.toml`.
+//! A day's build script calls `generate_tests`, which emits one test per
+//! fixture per recorded answer; the day includes them next to a `solve`
+//! function:
+//!
+//! ```ignore
+//! #[cfg(test)]
+//! mod fixtures {
+//!     use super::*;
+//!
+//!     fn solve(part: u8, input: &str) -> Answer {
+//!         solution::solve::<Day01>(part, input).unwrap()
+//!     }
+//!
+//!     include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+//! }
+//! ```
+//!
+//! Adding a case is then a matter of dropping in two files.
+
+use std::{
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    verify::{diff, Answers},
+    Answer,
+};
+
+pub const FIXTURES_DIR: &str = "tests/fixtures";
+
+#[derive(Debug)]
+pub struct Fixture {
+    pub name: String,
+    pub input: PathBuf,
+    pub answers: Answers,
+}
+
+/// The fixtures of `day` under the workspace at `root`.
+pub fn dir(root: &Path, day: &str) -> PathBuf {
+    root.join(FIXTURES_DIR).join(day)
+}
+
+/// Every `.txt` in `dir` with the answers from its sidecar, by name.
+pub fn discover(dir: &Path) -> io::Result<Vec<Fixture>> {
+    let mut fixtures = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let input = entry?.path();
+
+        if input.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+
+        let answers = match fs::read_to_string(input.with_extension("toml")) {
+            Ok(text) => Answers::parse(&text).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", input.display(), e),
+                )
+            })?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Answers::default(),
+            Err(e) => return Err(e),
+        };
+
+        fixtures.push(Fixture {
+            name: input.file_stem().unwrap().to_string_lossy().into_owned(),
+            input,
+            answers,
+        });
+    }
+
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(fixtures)
+}
+
+/// Turns a fixture name like `overlapping-words` into a test name.
+fn identifier(name: &str) -> String {
+    let ident = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>()
+        .to_lowercase();
+
+    match ident.chars().next() {
+        Some(c) if c.is_ascii_digit() => format!("_{}", ident),
+        _ => ident,
+    }
+}
+
+/// The tests for `fixtures`: `<name>_part<N>` for each recorded answer.
+pub fn tests(fixtures: &[Fixture]) -> String {
+    // Keeps `solve` in use, and its signature checked, for a day which has
+    // no fixtures yet.
+    let mut out = String::from("const _: fn(u8, &str) -> aoc_utils::Answer = solve;\n\n");
+
+    for fixture in fixtures {
+        for part in [1, 2] {
+            if fixture.answers.get(part).is_none() {
+                continue;
+            }
+
+            writeln!(
+                out,
+                "#[test]\nfn {}_part{}() {{\n    aoc_utils::fixtures::check({:?}, {}, solve);\n}}\n",
+                identifier(&fixture.name),
+                part,
+                fixture.input.display().to_string(),
+                part
+            )
+            .unwrap();
+        }
+    }
+
+    out
+}
+
+/// Writes the fixture tests of the crate being built to
+/// `$OUT_DIR/fixtures.rs`. Meant to be called from a day's `build.rs`.
+pub fn generate_tests() {
+    let manifest = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let day = std::env::var("CARGO_PKG_NAME").unwrap();
+    let dir = dir(manifest.parent().unwrap(), &day);
+
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let fixtures = match discover(&dir) {
+        Ok(fixtures) => fixtures,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => panic!("{}: {}", dir.display(), e),
+    };
+
+    let out = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("fixtures.rs");
+    fs::write(out, tests(&fixtures)).unwrap();
+}
+
+/// Solves `part` of the fixture at `input` and compares the answer with its
+/// sidecar.
+pub fn check(input: &str, part: u8, solve: impl FnOnce(u8, &str) -> Answer) {
+    let path = Path::new(input);
+    let text = fs::read_to_string(path).unwrap();
+    let answers =
+        Answers::parse(&fs::read_to_string(path.with_extension("toml")).unwrap()).unwrap();
+    let expected = answers.get(part).unwrap();
+
+    let actual = solve(part, &text);
+
+    if actual != Answer::parse(expected) {
+        panic!(
+            "{} part {}:\n{}",
+            path.display(),
+            part,
+            diff(expected, &actual.to_string())
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_tests() {
+        let fixtures = [
+            Fixture {
+                name: "example1".to_string(),
+                input: PathBuf::from("/f/example1.txt"),
+                answers: Answers {
+                    part1: Some("142".to_string()),
+                    part2: None,
+                },
+            },
+            Fixture {
+                name: "2-overlapping-words".to_string(),
+                input: PathBuf::from("/f/2-overlapping-words.txt"),
+                answers: Answers {
+                    part1: Some("1".to_string()),
+                    part2: Some("2".to_string()),
+                },
+            },
+        ];
+
+        let tests = tests(&fixtures);
+        assert!(tests.contains(
+            "fn example1_part1() {\n    aoc_utils::fixtures::check(\"/f/example1.txt\", 1, solve);\n}"
+        ));
+        assert!(!tests.contains("example1_part2"));
+        assert!(tests.contains("fn _2_overlapping_words_part1()"));
+        assert!(tests.contains("fn _2_overlapping_words_part2()"));
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = std::env::temp_dir().join(format!("aoc-fixtures-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("sum.txt"), "1 2 3\n").unwrap();
+        fs::write(dir.join("sum.toml"), "part1 = 6\n").unwrap();
+        fs::write(dir.join("notes.md"), "not a fixture").unwrap();
+
+        let fixtures = discover(&dir).unwrap();
+        assert_eq!(fixtures.len(), 1);
+        assert_eq!(fixtures[0].answers.get(1), Some("6"));
+
+        let input = fixtures[0].input.to_str().unwrap();
+        let sum = |_: u8, input: &str| {
+            input
+                .split_whitespace()
+                .map(|n| n.parse::<u32>().unwrap())
+                .sum::<u32>()
+                .into()
+        };
+        check(input, 1, sum);
+
+        let wrong = std::panic::catch_unwind(|| check(input, 1, |_, _| Answer::Unsigned(7)));
+        assert!(wrong.is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,3 +1,14 @@
 mod aoc_utils;
+pub mod answer;
+pub mod extract;
+pub mod fetch;
+pub mod fixtures;
+pub mod math;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod verify;
 
+pub use crate::answer::Answer;
 pub use crate::aoc_utils::*;
+pub use crate::solution::{LineError, Solution};
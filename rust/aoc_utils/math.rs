@@ -0,0 +1,409 @@
+//! Number theory helpers shared between the days.
+//!
+//! Everything is generic over `Integer`, so callers which need more headroom
+//! can simply use `u128`/`i128`. Functions which can overflow come in a
+//! `checked_` flavour returning `None` instead of panicking.
+
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+}
+
+/// Integers which can be negative, needed by the Bézout-based helpers.
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_rem(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_rem(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+/// `|n|`, or `None` for the `MIN` of a signed type, whose magnitude does not
+/// fit.
+fn checked_abs<T: Integer>(n: T) -> Option<T> {
+    if n < T::ZERO {
+        T::ZERO.checked_sub(n)
+    } else {
+        Some(n)
+    }
+}
+
+/// Greatest common divisor, always non-negative, or `None` if it does not
+/// fit in `T`. That only happens for a signed `MIN` with 0 or itself.
+pub fn checked_gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let mut a = a;
+    let mut b = b;
+
+    while b != T::ZERO {
+        let t = b;
+        // Only `MIN % -1` fails here, and its remainder is 0.
+        b = a.checked_rem(b).unwrap_or(T::ZERO);
+        a = t;
+    }
+
+    checked_abs(a)
+}
+
+/// Greatest common divisor, always non-negative. `gcd(0, 0)` is 0. Panics
+/// only when the result does not fit in `T`, as for `gcd(i64::MIN, 0)`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).unwrap_or_else(|| panic!("gcd({:?}, {:?}) overflows", a, b))
+}
+
+/// Least common multiple, or `None` if it does not fit in `T`.
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    checked_abs(a / checked_gcd(a, b)?)?.checked_mul(checked_abs(b)?)
+}
+
+/// Least common multiple. Divides before multiplying, so it only panics when
+/// the result itself does not fit in `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm({:?}, {:?}) overflows", a, b))
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`, or
+/// `None` if they do not fit in `T`.
+pub fn checked_extended_gcd<T: Signed>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (T::ONE, T::ZERO);
+    let (mut old_t, mut t) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        // Only `MIN % -1` fails here, and its remainder is 0.
+        let next = old_r.checked_rem(r).unwrap_or(T::ZERO);
+
+        // `r` is the gcd. Stop before computing the next coefficients, which
+        // are `a / g` and `b / g` up to sign and need not fit.
+        if next == T::ZERO {
+            (old_r, old_s, old_t) = (r, s, t);
+            break;
+        }
+
+        // `r` is not ±1 here, so the division cannot overflow.
+        let q = old_r / r;
+
+        (old_r, r) = (r, next);
+        (old_s, s) = (s, old_s.checked_sub(q.checked_mul(s)?)?);
+        (old_t, t) = (t, old_t.checked_sub(q.checked_mul(t)?)?);
+    }
+
+    if old_r < T::ZERO {
+        Some((
+            T::ZERO.checked_sub(old_r)?,
+            T::ZERO.checked_sub(old_s)?,
+            T::ZERO.checked_sub(old_t)?,
+        ))
+    } else {
+        Some((old_r, old_s, old_t))
+    }
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`. Panics
+/// only when the gcd does not fit in `T`, as for `extended_gcd(i64::MIN, 0)`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    checked_extended_gcd(a, b).unwrap_or_else(|| panic!("extended_gcd({:?}, {:?}) overflows", a, b))
+}
+
+/// `a` modulo `m`, in `0..m` for a positive `m`.
+pub fn rem_euclid<T: Integer>(a: T, m: T) -> T {
+    let r = a % m;
+
+    if r < T::ZERO {
+        r + m
+    } else {
+        r
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse<T: Signed>(a: T, m: T) -> Option<T> {
+    if m <= T::ONE {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(rem_euclid(a, m), m);
+
+    if g == T::ONE {
+        Some(rem_euclid(x, m))
+    } else {
+        None
+    }
+}
+
+/// Solves the system `x ≡ r (mod m)` for every `(r, m)` in `congruences`,
+/// whose moduli need not be coprime. Returns `(x, lcm of the moduli)` with
+/// `x` in `0..lcm`, or `None` if the congruences contradict each other or
+/// the arithmetic overflows.
+pub fn crt<T: Signed>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences
+        .iter()
+        .try_fold((T::ZERO, T::ONE), |(r1, m1), &(r2, m2)| {
+            if m2 <= T::ZERO {
+                return None;
+            }
+
+            let r2 = rem_euclid(r2, m2);
+            let (g, p, _) = extended_gcd(m1, m2);
+            let diff = r2.checked_sub(r1)?;
+
+            if diff % g != T::ZERO {
+                return None;
+            }
+
+            // m1 * k ≡ r2 - r1 (mod m2), with k taken modulo m2 / g.
+            let step = m2 / g;
+            let k = rem_euclid(diff / g, step).checked_mul(rem_euclid(p, step))?;
+            let k = rem_euclid(k, step);
+            let m = (m1 / g).checked_mul(m2)?;
+            let x = m1.checked_mul(k)?.checked_add(r1)?;
+
+            Some((rem_euclid(x, m), m))
+        })
+}
+
+/// The largest `r` with `r * r <= n`. Panics for a negative `n`.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    assert!(n >= T::ZERO, "isqrt of negative number {:?}", n);
+
+    let two = T::ONE + T::ONE;
+
+    if n < two {
+        return n;
+    }
+
+    let mut x = n / two;
+    let mut y = (x + n / x) / two;
+
+    while y < x {
+        x = y;
+        y = (x + n / x) / two;
+    }
+
+    x
+}
+
+/// `n` choose `k`, or `None` if it does not fit in `T`. Intermediate values
+/// stay below the result, so this only fails when the result overflows.
+pub fn binomial<T: Integer>(n: T, k: T) -> Option<T> {
+    if k < T::ZERO || k > n {
+        return Some(T::ZERO);
+    }
+
+    let k = k.min(n - k);
+    let mut result = T::ONE;
+    let mut i = T::ZERO;
+
+    while i < k {
+        // result * (n - i) is divisible by i + 1, so divide out their common
+        // factor first to keep the product as small as possible.
+        let divisor = i + T::ONE;
+        let g = gcd(result, divisor);
+
+        result = (result / g).checked_mul((n - i) / (divisor / g))?;
+        i = divisor;
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(gcd(12_u64, 18), 6);
+        assert_eq!(gcd(-12_i64, 18), 6);
+        assert_eq!(lcm(4_u32, 6), 12);
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(gcd(i64::MIN, -1), 1);
+        assert_eq!(gcd(i64::MIN, 6), 2);
+        assert_eq!(checked_gcd(i64::MIN, 0), None);
+        assert_eq!(checked_gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(checked_lcm(i64::MIN, 1), None);
+        assert_eq!(checked_lcm(i64::MIN, -1), None);
+        assert_eq!(checked_lcm(i64::MIN + 1, -1), Some(i64::MAX));
+        assert_eq!(checked_lcm(-4_i64, 6), Some(12));
+        assert_eq!(extended_gcd(240_i64, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(i64::MIN, -1), (1, 0, -1));
+        assert_eq!(extended_gcd(i64::MIN, 1), (1, 0, 1));
+        assert_eq!(checked_extended_gcd(i64::MIN, 0), None);
+        assert_eq!(mod_inverse(3_i32, 11), Some(4));
+        assert_eq!(mod_inverse(4_i32, 8), None);
+        assert_eq!(crt(&[(2_i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(3_i64, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[(1_i64, 4), (2, 6)]), None);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(binomial(5_u32, 2), Some(10));
+        assert_eq!(binomial(67_u64, 33), Some(14226520737620288370));
+        assert_eq!(binomial(68_u64, 34), None);
+    }
+
+    #[test]
+    fn test_extended_gcd_edges() {
+        let edges = [
+            i64::MIN,
+            i64::MIN + 1,
+            -2,
+            -1,
+            0,
+            1,
+            2,
+            i64::MAX - 1,
+            i64::MAX,
+        ];
+
+        for a in edges {
+            for b in edges {
+                match checked_extended_gcd(a, b) {
+                    Some((g, x, y)) => {
+                        assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+                        assert_eq!(Some(g), checked_gcd(a, b));
+                    }
+                    None => assert_eq!(checked_gcd(a, b), None),
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn gcd_divides_both(a in any::<u64>(), b in any::<u64>()) {
+            let g = gcd(a, b);
+
+            if g == 0 {
+                prop_assert_eq!((a, b), (0, 0));
+            } else {
+                prop_assert_eq!(a % g, 0);
+                prop_assert_eq!(b % g, 0);
+                prop_assert_eq!(gcd(a / g, b / g), 1);
+            }
+        }
+
+        #[test]
+        fn lcm_matches_wide_arithmetic(a in any::<u64>(), b in any::<u64>()) {
+            let wide = if a == 0 || b == 0 {
+                0
+            } else {
+                a as u128 * b as u128 / gcd(a, b) as u128
+            };
+
+            prop_assert_eq!(checked_lcm(a, b).map(u128::from), u64::try_from(wide).ok().map(u128::from));
+            prop_assert_eq!(lcm(a as u128, b as u128), wide);
+        }
+
+        #[test]
+        fn extended_gcd_is_bezout(a in any::<i64>(), b in any::<i64>()) {
+            let (g, x, y) = extended_gcd(a as i128, b as i128);
+
+            prop_assert_eq!(a as i128 * x + b as i128 * y, g);
+            prop_assert_eq!(g, gcd(a as i128, b as i128));
+        }
+
+        #[test]
+        fn checked_extended_gcd_is_bezout(a in any::<i64>(), b in any::<i64>()) {
+            match checked_extended_gcd(a, b) {
+                Some((g, x, y)) => {
+                    prop_assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+                    prop_assert_eq!(Some(g), checked_gcd(a, b));
+                }
+                None => prop_assert_eq!(checked_gcd(a, b), None),
+            }
+        }
+
+        #[test]
+        fn mod_inverse_inverts(a in any::<i64>(), m in 2..i64::MAX) {
+            match mod_inverse(a as i128, m as i128) {
+                Some(x) => prop_assert_eq!(rem_euclid(a as i128 * x, m as i128), 1),
+                None => prop_assert_ne!(gcd(a, m), 1),
+            }
+        }
+
+        #[test]
+        fn crt_solves_all_congruences(
+            congruences in prop::collection::vec((0..50_i64, 1..30_i64), 0..4),
+        ) {
+            let modulus = congruences.iter().fold(1, |acc, (_, m)| lcm(acc, *m));
+            let brute_force = (0..modulus)
+                .find(|x| congruences.iter().all(|(r, m)| x % m == r % m));
+
+            prop_assert_eq!(crt(&congruences), brute_force.map(|x| (x, modulus)));
+        }
+
+        #[test]
+        fn isqrt_is_floor(n in any::<u64>()) {
+            let r = isqrt(n) as u128;
+
+            prop_assert!(r * r <= n as u128);
+            prop_assert!((r + 1) * (r + 1) > n as u128);
+        }
+
+        #[test]
+        fn binomial_follows_pascal(n in 1..120_u128, k in 1..120_u128) {
+            let sum = binomial(n - 1, k - 1)
+                .zip(binomial(n - 1, k))
+                .and_then(|(a, b)| a.checked_add(b));
+
+            if let Some(sum) = sum {
+                prop_assert_eq!(binomial(n, k), Some(sum));
+            }
+
+            prop_assert_eq!(binomial(n as u64, k as u64).map(u128::from),
+                binomial(n, k).and_then(|c| u64::try_from(c).ok()).map(u128::from));
+        }
+    }
+}
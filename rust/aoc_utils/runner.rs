@@ -0,0 +1,380 @@
+//! Runs a day's parse and parts while measuring how long each one takes and
+//! how much it allocates.
+//!
+//! Allocations are only counted when the binary installs the counting
+//! allocator:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOC: aoc_utils::runner::CountingAlloc = aoc_utils::runner::CountingAlloc;
+//! ```
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use serde_json::{json, Map, Value};
+
+use crate::{Answer, Solution};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// A global allocator which forwards to `System` and keeps count of the
+/// allocations made and of the bytes in use.
+pub struct CountingAlloc;
+
+fn grow(size: usize) {
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+fn shrink(size: usize) {
+    CURRENT.fetch_sub(size, Ordering::Relaxed);
+}
+
+// SAFETY: every call is forwarded unchanged to `System`; the counters are
+// only bookkeeping on the side.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            grow(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            grow(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+
+        if !new_ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            shrink(layout.size());
+            grow(new_size);
+        }
+
+        new_ptr
+    }
+}
+
+/// What a single stage cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metrics {
+    pub elapsed: Duration,
+    pub allocations: u64,
+    /// The most memory in use at once during the stage, on top of what was
+    /// already in use when it started.
+    pub peak_bytes: usize,
+}
+
+impl Metrics {
+    /// Runs `f` and returns its result along with what it cost.
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Metrics) {
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let current = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(current, Ordering::Relaxed);
+
+        let start = Instant::now();
+        let value = f();
+        let elapsed = start.elapsed();
+
+        let metrics = Metrics {
+            elapsed,
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+            peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(current),
+        };
+
+        (value, metrics)
+    }
+
+    fn to_json(self) -> Map<String, Value> {
+        let mut map = Map::new();
+        map.insert("time_ns".into(), json!(self.elapsed.as_nanos() as u64));
+        map.insert("allocations".into(), json!(self.allocations));
+        map.insert("peak_bytes".into(), json!(self.peak_bytes));
+        map
+    }
+}
+
+impl fmt::Display for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.2?}, {} allocations, peak {}",
+            self.elapsed,
+            self.allocations,
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        b if b < 1 << 10 => format!("{} B", b),
+        b if b < 1 << 20 => format!("{:.1} KiB", b as f64 / (1 << 10) as f64),
+        b => format!("{:.1} MiB", b as f64 / (1 << 20) as f64),
+    }
+}
+
+#[derive(Debug)]
+enum Outcome {
+    Parsed,
+    Answer(String),
+    Error(String),
+}
+
+#[derive(Debug)]
+struct Stage {
+    name: String,
+    outcome: Outcome,
+    metrics: Metrics,
+}
+
+/// Measures the stages of a day and prints them with their answers once
+/// `finish` is called, as text or, with `--json`, as a JSON object keyed by
+/// stage.
+#[derive(Debug)]
+pub struct Runner {
+    json: bool,
+    stages: Vec<Stage>,
+}
+
+impl Runner {
+    pub fn new(json: bool) -> Self {
+        Self {
+            json,
+            stages: Vec::new(),
+        }
+    }
+
+    /// Emits JSON when the program was passed `--json`.
+    pub fn from_args() -> Self {
+        Self::new(Args::from_env().json())
+    }
+
+    pub fn parse<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let (value, metrics) = Metrics::measure(f);
+        self.push("parse".to_string(), Outcome::Parsed, metrics);
+        value
+    }
+
+    pub fn part<A: fmt::Display>(&mut self, part: u8, f: impl FnOnce() -> A) {
+        let (answer, metrics) = Metrics::measure(f);
+        self.push(
+            format!("part{}", part),
+            Outcome::Answer(answer.to_string()),
+            metrics,
+        );
+    }
+
+    /// Like `part`, for parts which can fail; errors are reported instead of
+    /// an answer.
+    pub fn try_part<A, E>(&mut self, part: u8, f: impl FnOnce() -> Result<A, E>)
+    where
+        A: fmt::Display,
+        E: fmt::Display,
+    {
+        let (result, metrics) = Metrics::measure(f);
+        let outcome = match result {
+            Ok(answer) => Outcome::Answer(answer.to_string()),
+            Err(e) => Outcome::Error(e.to_string()),
+        };
+
+        self.push(format!("part{}", part), outcome, metrics);
+    }
+
+    /// Parses `input` as `S` and runs both of its parts, then reports them.
+    /// Input which cannot be parsed ends the program.
+    pub fn run<S: Solution>(mut self, input: &str) {
+        let parsed = match self.parse(|| S::parse(input)) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        self.try_part(1, || S::part1(&parsed).map(Into::<Answer>::into));
+        self.try_part(2, || S::part2(&parsed).map(Into::<Answer>::into));
+        self.finish();
+    }
+
+    fn push(&mut self, name: String, outcome: Outcome, metrics: Metrics) {
+        self.stages.push(Stage {
+            name,
+            outcome,
+            metrics,
+        });
+    }
+
+    pub fn finish(self) {
+        if self.json {
+            println!("{:#}", self.to_json());
+            return;
+        }
+
+        for stage in &self.stages {
+            let label = match stage.name.strip_prefix("part") {
+                Some(n) => format!("Part {}", n),
+                None => "Parse".to_string(),
+            };
+
+            match &stage.outcome {
+                Outcome::Parsed => println!("{} ({})", label, stage.metrics),
+                // Pictures start on a line of their own to keep their rows
+                // aligned.
+                Outcome::Answer(answer) if answer.contains('\n') => {
+                    println!("{} ({}):\n{}", label, stage.metrics, answer)
+                }
+                Outcome::Answer(answer) => println!("{}: {} ({})", label, answer, stage.metrics),
+                Outcome::Error(e) => eprintln!("{}: {} ({})", label, e, stage.metrics),
+            }
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let stages = self
+            .stages
+            .iter()
+            .map(|stage| {
+                let mut map = stage.metrics.to_json();
+
+                match &stage.outcome {
+                    Outcome::Parsed => {}
+                    Outcome::Answer(answer) => {
+                        map.insert("answer".into(), json!(answer));
+                    }
+                    Outcome::Error(e) => {
+                        map.insert("error".into(), json!(e));
+                    }
+                }
+
+                (stage.name.clone(), Value::Object(map))
+            })
+            .collect::<Map<_, _>>();
+
+        Value::Object(stages)
+    }
+}
+
+/// A day's command line arguments, without the `--json` the runner reads
+/// itself. Flags are taken out wherever they appear, which leaves the
+/// subcommand and its operands for the day to match on.
+#[derive(Debug)]
+pub struct Args {
+    json: bool,
+    args: Vec<String>,
+}
+
+impl Args {
+    pub fn new(args: impl IntoIterator<Item = String>) -> Self {
+        let (json, args): (Vec<_>, Vec<_>) = args.into_iter().partition(|a| a == "--json");
+
+        Self {
+            json: !json.is_empty(),
+            args,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(std::env::args().skip(1))
+    }
+
+    pub fn json(&self) -> bool {
+        self.json
+    }
+
+    /// Takes out every `flag`, returning whether there was one.
+    pub fn flag(&mut self, flag: &str) -> bool {
+        let len = self.args.len();
+        self.args.retain(|a| a != flag);
+        self.args.len() != len
+    }
+
+    /// Takes out `flag` and the value after it. A `flag` with nothing after
+    /// it is left in place, for the day to reject as an unknown argument.
+    pub fn value(&mut self, flag: &str) -> Option<String> {
+        let idx = self.args.iter().position(|a| a == flag)?;
+
+        if idx + 1 == self.args.len() {
+            return None;
+        }
+
+        let value = self.args.remove(idx + 1);
+        self.args.remove(idx);
+        Some(value)
+    }
+
+    /// What is left once the flags have been taken out.
+    pub fn rest(&self) -> Vec<&str> {
+        self.args.iter().map(String::as_str).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runner_json() {
+        let mut runner = Runner::new(true);
+        let nums = runner.parse(|| vec![1, 2, 3]);
+        runner.part(1, || nums.iter().sum::<i32>());
+        runner.try_part(2, || "x".parse::<i32>());
+
+        let json = runner.to_json();
+        assert_eq!(json["part1"]["answer"], "6");
+        assert_eq!(json["part2"]["error"], "invalid digit found in string");
+        assert!(json["parse"].get("answer").is_none());
+        assert!(json["parse"]["time_ns"].is_u64());
+    }
+
+    #[test]
+    fn test_args() {
+        let args = [
+            "--strict", "convert", "--json", "seed", "--ties", "shared", "soil",
+        ];
+        let mut args = Args::new(args.map(String::from));
+
+        assert!(args.json());
+        assert!(args.flag("--strict"));
+        assert!(!args.flag("--plain"));
+        assert_eq!(args.value("--ties"), Some("shared".to_string()));
+        assert_eq!(args.rest(), ["convert", "seed", "soil"]);
+
+        let mut args = Args::new(["report", "--ties"].map(String::from));
+        assert!(!args.json());
+        assert_eq!(args.value("--ties"), None);
+        assert_eq!(args.rest(), ["report", "--ties"]);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+    }
+}
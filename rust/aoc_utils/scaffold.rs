@@ -0,0 +1,272 @@
+//! Creates the crate for a new day with the layout every day shares: a
+//! library with `parse`, `part1` and `part2` behind a `Solution`, a thin
+//! binary around the runner, a build script for the fixture tests, an empty
+//! `answers.toml` and an empty input to be replaced by `aoc fetch`.
+//!
+//! Until they are written, both parts answer with an error rather than
+//! panicking, so the new day already runs and benchmarks.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{fixtures, verify::Answers};
+
+/// The crate which benchmarks every day, relative to the workspace, and
+/// its benchmark which lists them.
+const BENCH_MANIFEST: &str = "bench/Cargo.toml";
+const BENCH_DAYS: &str = "bench/benches/days.rs";
+
+const CARGO_TOML: &str = r#"[package]
+name = "{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc_utils = { workspace = true }
+
+[build-dependencies]
+aoc_utils = { workspace = true }
+"#;
+
+const BUILD_RS: &str = r#"fn main() {
+    aoc_utils::fixtures::generate_tests();
+}
+"#;
+
+const LIB_RS: &str = r#"use aoc_utils::{solution, Answer, Solution};
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+pub fn part1(_lines: &[&str]) -> solution::Result<u64> {
+    Err("not solved yet".into())
+}
+
+pub fn part2(_lines: &[&str]) -> solution::Result<u64> {
+    Err("not solved yet".into())
+}
+
+pub struct {Day};
+
+impl Solution for {Day} {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> solution::Result<Self::Input<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        part1(lines)
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        part2(lines)
+    }
+}
+
+#[cfg(test)]
+mod fixtures {
+    use super::*;
+
+    fn solve(part: u8, input: &str) -> Answer {
+        solution::solve::<{Day}>(part, input).unwrap()
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
+"#;
+
+const MAIN_RS: &str = r#"use aoc_utils::runner::{CountingAlloc, Runner};
+use {day}::{Day};
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+fn main() {
+    Runner::from_args().run::<{Day}>(include_str!("./input"));
+}
+"#;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day's crate is already there; nothing was written.
+    Exists(PathBuf),
+    Io(io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(e: io::Error) -> Self {
+        ScaffoldError::Io(e)
+    }
+}
+
+/// Creates `dayNN` under the workspace at `root` along with its fixture
+/// directory, and adds it to the bench crate and its benchmark. Returns what
+/// was written.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let name = format!("day{:02}", day);
+    let dir = root.join(&name);
+
+    if dir.exists() {
+        return Err(ScaffoldError::Exists(dir));
+    }
+
+    let src = dir.join("src");
+    fs::create_dir_all(&src)?;
+
+    let mut written = Vec::new();
+    let mut write = |path: PathBuf, template: &str| -> io::Result<()> {
+        let text = template
+            .replace("{day}", &name)
+            .replace("{Day}", &format!("Day{:02}", day));
+        fs::write(&path, text)?;
+        written.push(path);
+        Ok(())
+    };
+
+    write(dir.join("Cargo.toml"), CARGO_TOML)?;
+    write(dir.join("build.rs"), BUILD_RS)?;
+    write(src.join("lib.rs"), LIB_RS)?;
+    write(src.join("main.rs"), MAIN_RS)?;
+    // `include_str!` needs the input to exist for the day to build at all.
+    write(src.join("input"), "")?;
+
+    Answers::default().save(&src)?;
+    written.push(src.join(crate::verify::ANSWERS_FILE));
+
+    let fixtures = fixtures::dir(root, &name);
+    fs::create_dir_all(&fixtures)?;
+    written.push(fixtures);
+
+    let bench = root.join(BENCH_MANIFEST);
+    if bench.exists() {
+        let manifest = fs::read_to_string(&bench)?;
+        fs::write(&bench, register(&manifest, &name))?;
+        written.push(bench);
+    }
+
+    let bench = root.join(BENCH_DAYS);
+    if bench.exists() {
+        let days = fs::read_to_string(&bench)?;
+        fs::write(&bench, register_bench(&days, day))?;
+        written.push(bench);
+    }
+
+    Ok(written)
+}
+
+/// Adds `day` to the benchmarks, after the last day already there.
+fn register_bench(days: &str, day: u8) -> String {
+    let call = format!("    bench_day::<day{:02}::Day{:02}>(c, {});", day, day, day);
+    let mut lines = days.lines().collect::<Vec<_>>();
+
+    if lines.contains(&call.as_str()) {
+        return days.to_string();
+    }
+
+    match lines
+        .iter()
+        .rposition(|line| line.trim_start().starts_with("bench_day::<"))
+    {
+        Some(i) => lines.insert(i + 1, &call),
+        None => return days.to_string(),
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Adds `day` as a path dependency to a manifest, after the last day
+/// already there.
+fn register(manifest: &str, day: &str) -> String {
+    let dependency = format!("{} = {{ path = \"../{}\" }}", day, day);
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+
+    if lines.contains(&dependency.as_str()) {
+        return manifest.to_string();
+    }
+
+    let is_day = |line: &&str| line.starts_with("day") && line.contains("path = ");
+    let at = match lines.iter().rposition(is_day) {
+        Some(i) => i + 1,
+        None => match lines.iter().position(|line| *line == "[dependencies]") {
+            Some(i) => i + 1,
+            None => {
+                lines.push("");
+                lines.push("[dependencies]");
+                lines.len()
+            }
+        },
+    };
+
+    lines.insert(at, &dependency);
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("bench")).unwrap();
+        fs::write(
+            root.join(BENCH_MANIFEST),
+            "[dependencies]\nday09 = { path = \"../day09\" }\nserde_json = \"1.0\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("bench/benches")).unwrap();
+        fs::write(
+            root.join(BENCH_DAYS),
+            "fn days(c: &mut Criterion) {\n    bench_day::<day09::Day09>(c, 9);\n}\n",
+        )
+        .unwrap();
+
+        new_day(&root, 10).unwrap();
+
+        let main = fs::read_to_string(root.join("day10/src/main.rs")).unwrap();
+        assert!(main.contains("use day10::Day10;"));
+        assert!(main.contains("run::<Day10>"));
+        assert_eq!(
+            fs::read_to_string(root.join("day10/src/input")).unwrap(),
+            ""
+        );
+        assert!(root.join("day10/src/answers.toml").exists());
+        assert!(root.join("tests/fixtures/day10").is_dir());
+        assert_eq!(
+            fs::read_to_string(root.join(BENCH_MANIFEST)).unwrap(),
+            "[dependencies]\nday09 = { path = \"../day09\" }\nday10 = { path = \"../day10\" }\nserde_json = \"1.0\"\n"
+        );
+        assert!(fs::read_to_string(root.join(BENCH_DAYS)).unwrap().contains(
+            "    bench_day::<day09::Day09>(c, 9);\n    bench_day::<day10::Day10>(c, 10);\n}"
+        ));
+
+        fs::write(root.join("day10/src/lib.rs"), "// solved").unwrap();
+        assert!(matches!(new_day(&root, 10), Err(ScaffoldError::Exists(_))));
+        assert_eq!(
+            fs::read_to_string(root.join("day10/src/lib.rs")).unwrap(),
+            "// solved"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,90 @@
+//! The shape every day shares, so tooling can run any of them the same way:
+//! parse the input once, then answer both parts from what was parsed.
+
+use std::{error, fmt};
+
+use crate::Answer;
+
+/// Why a day could not parse its input or answer a part.
+pub type Error = Box<dyn error::Error + Send + Sync>;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A value on a line of the input, numbered from 1, which could not be
+/// parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LineError {
+    pub line: usize,
+    pub value: String,
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: cannot parse `{}`", self.line, self.value)
+    }
+}
+
+impl error::Error for LineError {}
+
+pub trait Solution {
+    /// The parsed input, which may borrow from the text it came from.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Into<Answer>>;
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Into<Answer>>;
+}
+
+/// Answers `part` of `input`, as tooling which only knows the part's number
+/// needs to.
+pub fn solve<S: Solution>(part: u8, input: &str) -> Result<Answer> {
+    let input = S::parse(input)?;
+
+    match part {
+        1 => Ok(S::part1(&input)?.into()),
+        2 => Ok(S::part2(&input)?.into()),
+        _ => Err(format!("no part {}", part).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input<'a> = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            let nums = input.split_whitespace().map(str::parse::<i64>);
+            Ok(nums.collect::<std::result::Result<_, _>>()?)
+        }
+
+        fn part1(nums: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+            Ok(nums.iter().sum::<i64>())
+        }
+
+        fn part2(nums: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+            nums.iter()
+                .try_fold(1_i64, |product, n| product.checked_mul(*n))
+                .ok_or_else(|| "the product overflows".into())
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve::<Sum>(1, "1 2 -3 4").unwrap(), Answer::Signed(4));
+        assert_eq!(solve::<Sum>(2, "1 2 -3 4").unwrap(), Answer::Signed(-24));
+        assert!(solve::<Sum>(1, "1 two").is_err());
+        assert_eq!(
+            solve::<Sum>(2, "4294967296 4294967296")
+                .unwrap_err()
+                .to_string(),
+            "the product overflows"
+        );
+        assert!(solve::<Sum>(3, "1").is_err());
+    }
+}
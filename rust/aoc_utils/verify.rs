@@ -0,0 +1,221 @@
+//! Checks a day's answers against the ones recorded in the `answers.toml`
+//! next to its input:
+//!
+//! ```toml
+//! part1 = 35
+//! part2 = "46"
+//! ```
+//!
+//! Answers may be written as integers or strings, and are compared as an
+//! `Answer` read from the text a day prints.
+
+use std::{fmt, fs, io, path::Path};
+
+use toml::{Table, Value};
+
+use crate::Answer;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let table = text.parse::<Table>().map_err(|e| e.to_string())?;
+
+        let answer = |key: &str| match table.get(key) {
+            None => Ok(None),
+            Some(Value::Integer(n)) => Ok(Some(n.to_string())),
+            Some(Value::String(s)) => Ok(Some(s.clone())),
+            Some(other) => Err(format!(
+                "`{}` must be an integer or a string, not {}",
+                key,
+                other.type_str()
+            )),
+        };
+
+        Ok(Answers {
+            part1: answer("part1")?,
+            part2: answer("part2")?,
+        })
+    }
+
+    /// Reads `answers.toml` from `dir`, treating a missing file as having no
+    /// answers yet.
+    pub fn load(dir: &Path) -> io::Result<Answers> {
+        match fs::read_to_string(dir.join(ANSWERS_FILE)) {
+            Ok(text) => {
+                Answers::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        fs::write(dir.join(ANSWERS_FILE), self.to_toml())
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            2 => self.part2 = Some(answer),
+            _ => panic!("No part {}", part),
+        }
+    }
+
+    /// Writes numbers which fit a TOML integer as such and anything else as a
+    /// string.
+    pub fn to_toml(&self) -> String {
+        let mut table = Table::new();
+
+        for (key, answer) in [("part1", &self.part1), ("part2", &self.part2)] {
+            if let Some(answer) = answer {
+                let value = match answer.parse::<i64>() {
+                    Ok(n) if n.to_string() == *answer => Value::Integer(n),
+                    _ => Value::String(answer.clone()),
+                };
+
+                table.insert(key.to_string(), value);
+            }
+        }
+
+        table.to_string()
+    }
+}
+
+/// How a part's answer compares to the recorded one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// Nothing is recorded for the part yet.
+    Missing {
+        actual: String,
+    },
+    /// The part did not produce an answer.
+    Error(String),
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&str>, actual: Result<&str, &str>) -> Verdict {
+        match (expected, actual) {
+            (_, Err(e)) => Verdict::Error(e.to_string()),
+            (None, Ok(actual)) => Verdict::Missing {
+                actual: actual.to_string(),
+            },
+            (Some(expected), Ok(actual)) if Answer::parse(expected) == Answer::parse(actual) => {
+                Verdict::Pass
+            }
+            (Some(expected), Ok(actual)) => Verdict::Fail {
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL\n{}", diff(expected, actual).trim_end())
+            }
+            Verdict::Missing { actual } => write!(f, "missing (got {})", actual),
+            Verdict::Error(e) => write!(f, "error: {}", e),
+        }
+    }
+}
+
+/// A line-by-line diff, marking expected lines with `-` and actual ones
+/// with `+`. Lines which agree are kept for context.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+    let mut out = String::new();
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => out.push_str(&format!("  {}\n", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    out.push_str(&format!("- {}\n", e));
+                }
+
+                if let Some(a) = a {
+                    out.push_str(&format!("+ {}\n", a));
+                }
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("part1 = 35\npart2 = \"46\"\n").unwrap();
+        assert_eq!(answers.get(1), Some("35"));
+        assert_eq!(answers.get(2), Some("46"));
+
+        assert_eq!(Answers::parse("").unwrap(), Answers::default());
+        assert!(Answers::parse("part1 = 1.5").is_err());
+
+        let answers = Answers {
+            part1: Some("-7".to_string()),
+            part2: Some("340282366920938463463374607431768211455".to_string()),
+        };
+        assert_eq!(Answers::parse(&answers.to_toml()).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_verdicts() {
+        assert_eq!(Verdict::check(Some("35"), Ok("35")), Verdict::Pass);
+        assert_eq!(
+            Verdict::check(Some("#.\n.#"), Ok("#. \n.#\n")),
+            Verdict::Pass
+        );
+        assert_eq!(
+            Verdict::check(None, Ok("35")),
+            Verdict::Missing {
+                actual: "35".to_string()
+            }
+        );
+        assert_eq!(
+            Verdict::check(Some("35"), Err("boom")),
+            Verdict::Error("boom".to_string())
+        );
+        assert_eq!(
+            Verdict::check(Some("35"), Ok("36")).to_string(),
+            "FAIL\n- 35\n+ 36"
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff("#.#\n.#.", "#.#\n##.\n..."),
+            "  #.#\n- .#.\n+ ##.\n+ ...\n"
+        );
+    }
+}
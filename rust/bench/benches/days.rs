@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use aoc_utils::Solution;
+use bench::inputs;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Benchmarks `parse`, `part1` and `part2` of `day` on each of its inputs.
+/// Parts only run on the inputs they apply to.
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8) {
+    let inputs = inputs(day);
+    let mut group = c.benchmark_group(format!("day{:02}", day));
+
+    for input in &inputs {
+        let text = input.text.as_str();
+
+        group.bench_function(BenchmarkId::new("parse", input.name), |b| {
+            b.iter(|| S::parse(black_box(text)))
+        });
+
+        let parsed = S::parse(text).unwrap();
+
+        if input.part1 {
+            group.bench_function(BenchmarkId::new("part1", input.name), |b| {
+                b.iter(|| S::part1(black_box(&parsed)))
+            });
+        }
+
+        if input.part2 {
+            group.bench_function(BenchmarkId::new("part2", input.name), |b| {
+                b.iter(|| S::part2(black_box(&parsed)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c, 1);
+    bench_day::<day02::Day02>(c, 2);
+    bench_day::<day03::Day03>(c, 3);
+    bench_day::<day04::Day04>(c, 4);
+    bench_day::<day05::Day05>(c, 5);
+    bench_day::<day06::Day06>(c, 6);
+    bench_day::<day07::Day07>(c, 7);
+    bench_day::<day08::Day08>(c, 8);
+    bench_day::<day09::Day09>(c, 9);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3));
+    targets = days
+}
+criterion_main!(benches);
@@ -0,0 +1,143 @@
+//! Prints the mean time of every benchmark from criterion's last run next to
+//! the run before it, or next to a baseline saved on another commit:
+//!
+//!     cargo bench -p bench -- --save-baseline main
+//!     git checkout my-branch && cargo bench -p bench
+//!     cargo run -p bench --bin summary -- [--baseline main] [criterion-dir]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+#[derive(Debug)]
+struct Row {
+    day: String,
+    stage: String,
+    input: String,
+    mean: f64,
+    previous: Option<f64>,
+}
+
+/// The mean estimate, in nanoseconds, that criterion saved under `dir`.
+fn read_mean(dir: &Path) -> Option<f64> {
+    let estimates = fs::read_to_string(dir.join("estimates.json")).ok()?;
+    let estimates = serde_json::from_str::<Value>(&estimates).ok()?;
+
+    estimates["mean"]["point_estimate"].as_f64()
+}
+
+fn subdirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    dirs.sort();
+    dirs
+}
+
+fn name(path: &Path) -> String {
+    path.file_name().unwrap().to_string_lossy().into_owned()
+}
+
+/// The mean of the run before the last one, which criterion only keeps as
+/// the relative change between the two.
+fn read_previous(dir: &Path, mean: f64) -> Option<f64> {
+    read_mean(&dir.join("change")).map(|change| mean / (1.0 + change))
+}
+
+/// Walks `<group>/<function>/<input>/new` as laid out by criterion.
+fn collect(root: &Path, baseline: Option<&str>) -> Vec<Row> {
+    let mut rows = Vec::new();
+
+    for day in subdirs(root).iter().filter(|d| name(d).starts_with("day")) {
+        for stage in subdirs(day) {
+            for input in subdirs(&stage) {
+                if let Some(mean) = read_mean(&input.join("new")) {
+                    rows.push(Row {
+                        day: name(day),
+                        stage: name(&stage),
+                        input: name(&input),
+                        mean,
+                        previous: match baseline {
+                            Some(baseline) => read_mean(&input.join(baseline)),
+                            None => read_previous(&input, mean),
+                        },
+                    });
+                }
+            }
+        }
+    }
+
+    rows
+}
+
+fn format_time(ns: f64) -> String {
+    match ns {
+        ns if ns < 1e3 => format!("{:.1} ns", ns),
+        ns if ns < 1e6 => format!("{:.2} µs", ns / 1e3),
+        ns if ns < 1e9 => format!("{:.2} ms", ns / 1e6),
+        ns => format!("{:.2} s", ns / 1e9),
+    }
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let (baseline, root) = match args.as_slice() {
+        ["--baseline", name, rest @ ..] => (Some(*name), rest.first()),
+        rest => (None, rest.first()),
+    };
+
+    let root = root.map(PathBuf::from).unwrap_or_else(|| {
+        let target = std::env::var("CARGO_TARGET_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| Path::new(env!("CARGO_MANIFEST_DIR")).join("../target"));
+
+        target.join("criterion")
+    });
+
+    let rows = collect(&root, baseline);
+
+    if rows.is_empty() {
+        eprintln!(
+            "No benchmark results under {}; run `cargo bench -p bench` first",
+            root.display()
+        );
+        std::process::exit(1);
+    }
+
+    println!(
+        "{:<6} {:<6} {:<9} {:>11} {:>11} {:>8}",
+        "day", "stage", "input", "mean", "previous", "change"
+    );
+
+    for row in rows {
+        let (previous, change) = match row.previous {
+            Some(previous) => (
+                format_time(previous),
+                format!("{:+.1}%", (row.mean - previous) / previous * 100.0),
+            ),
+            None => ("-".to_string(), "-".to_string()),
+        };
+
+        println!(
+            "{:<6} {:<6} {:<9} {:>11} {:>11} {:>8}",
+            row.day,
+            row.stage,
+            row.input,
+            format_time(row.mean),
+            previous,
+            change
+        );
+    }
+}
@@ -0,0 +1,20 @@
+//! The examples given in each day's puzzle description, shared with the
+//! fixture tests.
+
+macro_rules! fixture {
+    ($day:literal, $name:literal) => {
+        include_str!(concat!("../../tests/fixtures/", $day, "/", $name, ".txt"))
+    };
+}
+
+pub const DAY01_PART1: &str = fixture!("day01", "example1");
+pub const DAY01_PART2: &str = fixture!("day01", "example2");
+pub const DAY02: &str = fixture!("day02", "example1");
+pub const DAY03: &str = fixture!("day03", "example1");
+pub const DAY04: &str = fixture!("day04", "example1");
+pub const DAY05: &str = fixture!("day05", "example1");
+pub const DAY06: &str = fixture!("day06", "example1");
+pub const DAY07: &str = fixture!("day07", "example1");
+pub const DAY08: &str = fixture!("day08", "example1");
+pub const DAY08_PART2: &str = fixture!("day08", "example3");
+pub const DAY09: &str = fixture!("day09", "example1");
@@ -0,0 +1,297 @@
+//! Deterministic generators for inputs much larger than the puzzle's own.
+//! Every generator uses a fixed seed so timings stay comparable across
+//! commits.
+
+use std::fmt::Write;
+
+/// A xorshift64* generator; good enough to shuffle puzzle input around.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number in `0..n`.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    /// A number in `lo..=hi`.
+    fn between(&mut self, lo: u64, hi: u64) -> u64 {
+        lo + self.below(hi - lo + 1)
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    /// `count` distinct numbers from `pool`, which is consumed.
+    fn sample(&mut self, pool: &mut Vec<u64>, count: usize) -> Vec<u64> {
+        (0..count)
+            .map(|_| pool.swap_remove(self.below(pool.len() as u64) as usize))
+            .collect()
+    }
+}
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub fn day01(lines: usize) -> String {
+    let mut rng = Rng::new(1);
+    let mut out = String::new();
+
+    for _ in 0..lines {
+        let mut line = (0..rng.between(8, 30))
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect::<String>();
+
+        for _ in 0..rng.between(1, 3) {
+            let at = rng.below(line.len() as u64) as usize;
+            line.insert(at, (b'1' + rng.below(9) as u8) as char);
+        }
+
+        for _ in 0..rng.below(3) {
+            let at = rng.below(line.len() as u64) as usize;
+            let word = *rng.pick(&WORDS);
+            line.insert_str(at, word);
+        }
+
+        writeln!(out, "{}", line).unwrap();
+    }
+
+    out
+}
+
+pub fn day02(games: usize) -> String {
+    let mut rng = Rng::new(2);
+    let mut out = String::new();
+
+    for id in 1..=games {
+        let draws = (0..rng.between(1, 6))
+            .map(|_| {
+                let mut colors = vec!["red", "green", "blue"];
+
+                (0..rng.between(1, 3))
+                    .map(|_| {
+                        let color = colors.swap_remove(rng.below(colors.len() as u64) as usize);
+                        format!("{} {}", rng.between(1, 20), color)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+
+        writeln!(out, "Game {}: {}", id, draws.join("; ")).unwrap();
+    }
+
+    out
+}
+
+pub fn day03(size: usize) -> String {
+    let mut rng = Rng::new(3);
+    let mut out = String::new();
+
+    for _ in 0..size {
+        let mut row = String::new();
+
+        while row.len() < size {
+            match rng.below(10) {
+                0..=5 => row.push('.'),
+                6 => row.push(*rng.pick(&['*', '#', '+', '$', '/', '@', '=', '%', '-', '&'])),
+                _ => {
+                    let digits = (rng.between(1, 3) as usize).min(size - row.len());
+
+                    for _ in 0..digits {
+                        row.push((b'0' + rng.below(10) as u8) as char);
+                    }
+
+                    // Keep numbers from running into each other.
+                    if row.len() < size {
+                        row.push('.');
+                    }
+                }
+            }
+        }
+
+        writeln!(out, "{}", row).unwrap();
+    }
+
+    out
+}
+
+pub fn day04(cards: usize) -> String {
+    let mut rng = Rng::new(4);
+    let mut out = String::new();
+
+    for id in 1..=cards {
+        let mut pool = (1..100).collect::<Vec<_>>();
+        let mut winning = rng.sample(&mut pool, 10);
+
+        // At most two matches per card keeps part 2's copies from exploding,
+        // and like the puzzle no card wins copies past the end of the table.
+        let matches = (rng.below(3) as usize).min(cards - id);
+        let mut holds = rng.sample(&mut winning.clone(), matches);
+        holds.extend(rng.sample(&mut pool, 25 - matches));
+
+        let format = |nums: &mut Vec<u64>| {
+            nums.sort();
+            nums.iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        writeln!(
+            out,
+            "Card {:>4}: {} | {}",
+            id,
+            format(&mut winning),
+            format(&mut holds)
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+pub fn day05(maps_per_layer: usize) -> String {
+    const SPACE: u64 = 1 << 32;
+
+    let mut rng = Rng::new(5);
+    let seeds = (0..10)
+        .map(|_| format!("{} {}", rng.below(SPACE), rng.between(50_000, 150_000)))
+        .collect::<Vec<_>>();
+
+    let mut out = format!("seeds: {}\n", seeds.join(" "));
+
+    for pair in CATEGORIES.windows(2) {
+        writeln!(out, "\n{}-to-{} map:", pair[0], pair[1]).unwrap();
+
+        let mut cuts = (1..maps_per_layer)
+            .map(|_| rng.below(SPACE))
+            .collect::<Vec<_>>();
+        cuts.extend([0, SPACE]);
+        cuts.sort();
+        cuts.dedup();
+
+        for range in cuts.windows(2) {
+            // Leave some gaps so unmapped values pass straight through.
+            if rng.below(5) == 0 {
+                continue;
+            }
+
+            let length = range[1] - range[0];
+            writeln!(out, "{} {} {}", rng.below(SPACE), range[0], length).unwrap();
+        }
+    }
+
+    out
+}
+
+pub fn day06(races: usize) -> String {
+    let mut rng = Rng::new(6);
+    let (times, distances): (Vec<_>, Vec<_>) = (0..races)
+        .map(|_| {
+            let time = rng.between(5_000, 9_999);
+            let distance = rng.between(1_000_000, time * time / 4 * 9 / 10);
+
+            (time.to_string(), distance.to_string())
+        })
+        .unzip();
+
+    format!(
+        "Time: {}\nDistance: {}\n",
+        times.join(" "),
+        distances.join(" ")
+    )
+}
+
+pub fn day07(hands: usize) -> String {
+    let mut rng = Rng::new(7);
+    let cards = "23456789TJQKA".chars().collect::<Vec<_>>();
+    let mut out = String::new();
+
+    for _ in 0..hands {
+        let hand = (0..5).map(|_| *rng.pick(&cards)).collect::<String>();
+        writeln!(out, "{} {}", hand, rng.between(1, 1_000)).unwrap();
+    }
+
+    out
+}
+
+/// One loop per ghost, each reaching its `Z` node after a prime multiple of
+/// the instruction length and then cycling back, the way puzzle inputs do.
+pub fn day08(ghosts: usize) -> String {
+    const PRIMES: [u64; 8] = [11, 13, 17, 19, 23, 29, 31, 37];
+
+    let mut rng = Rng::new(8);
+    let instructions = (0..40).map(|_| *rng.pick(&['L', 'R'])).collect::<String>();
+    let mut out = format!("{}\n\n", instructions);
+
+    for (ghost, prime) in PRIMES.iter().take(ghosts).enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (format!("G{}A", ghost), format!("G{}Z", ghost)),
+        };
+        let length = instructions.len() as u64 * prime;
+        let node = |i: u64| match i {
+            0 => start.clone(),
+            i if i == length => end.clone(),
+            i => format!("G{}N{}", ghost, i),
+        };
+
+        for i in 0..length {
+            let next = node(i + 1);
+            writeln!(out, "{} = ({}, {})", node(i), next, next).unwrap();
+        }
+
+        writeln!(out, "{} = ({}, {})", end, node(1), node(1)).unwrap();
+    }
+
+    out
+}
+
+pub fn day09(lines: usize) -> String {
+    let mut rng = Rng::new(9);
+    let mut out = String::new();
+
+    for _ in 0..lines {
+        let coefficients = (0..=rng.below(7))
+            .map(|_| rng.below(11) as i64 - 5)
+            .collect::<Vec<_>>();
+
+        let values = (0..21_i64)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, c| acc * x + c)
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+
+        writeln!(out, "{}", values.join(" ")).unwrap();
+    }
+
+    out
+}
@@ -0,0 +1,154 @@
+mod examples;
+mod generate;
+
+/// A named puzzle input and the parts it is meant to be solved with.
+#[derive(Debug)]
+pub struct Input {
+    pub name: &'static str,
+    pub text: String,
+    pub part1: bool,
+    pub part2: bool,
+}
+
+impl Input {
+    fn new(name: &'static str, text: impl Into<String>) -> Self {
+        Self {
+            name,
+            text: text.into(),
+            part1: true,
+            part2: true,
+        }
+    }
+
+    fn only(mut self, part: u8) -> Self {
+        self.part1 = part == 1;
+        self.part2 = part == 2;
+        self
+    }
+}
+
+/// The puzzle examples of `day` followed by a large generated input. Days
+/// which have none yet are not benchmarked.
+pub fn inputs(day: u8) -> Vec<Input> {
+    match day {
+        1 => vec![
+            Input::new("example", examples::DAY01_PART1).only(1),
+            Input::new("example2", examples::DAY01_PART2).only(2),
+            Input::new("large", generate::day01(10_000)),
+        ],
+        2 => vec![
+            Input::new("example", examples::DAY02),
+            Input::new("large", generate::day02(10_000)),
+        ],
+        3 => vec![
+            Input::new("example", examples::DAY03),
+            Input::new("large", generate::day03(140)),
+        ],
+        4 => vec![
+            Input::new("example", examples::DAY04),
+            Input::new("large", generate::day04(1_000)),
+        ],
+        5 => vec![
+            Input::new("example", examples::DAY05),
+            Input::new("large", generate::day05(40)),
+        ],
+        6 => vec![
+            Input::new("example", examples::DAY06),
+            Input::new("large", generate::day06(4)),
+        ],
+        7 => vec![
+            Input::new("example", examples::DAY07),
+            Input::new("large", generate::day07(1_000)),
+        ],
+        8 => vec![
+            Input::new("example", examples::DAY08),
+            Input::new("example2", examples::DAY08_PART2).only(2),
+            Input::new("large", generate::day08(6)),
+        ],
+        9 => vec![
+            Input::new("example", examples::DAY09),
+            Input::new("large", generate::day09(1_000)),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use day06::BoatModel;
+    use day07::{JokerKind, TiePolicy};
+
+    #[test]
+    fn test_examples() {
+        let first = |day| inputs(day).remove(0).text;
+
+        assert_eq!(day01::part1(&first(1)), Ok(142));
+        assert_eq!(day01::part2(&inputs(1)[1].text), Ok(281));
+        assert_eq!(day02::part1(&day02::parse(&first(2)).unwrap()), 8);
+        assert_eq!(day03::part1(&day03::parse(&first(3)).unwrap()), 4361);
+        assert_eq!(day04::part2(&day04::parse(&first(4)).unwrap()), 30);
+        assert_eq!(
+            day05::part2(&day05::Almanac::parse(&first(5)).unwrap()),
+            Ok(46)
+        );
+
+        let model = BoatModel::default();
+        assert_eq!(
+            day06::part2(&day06::parse_input(&first(6)).unwrap(), &model),
+            Ok(71503)
+        );
+
+        let hands = day07::parse_input(&first(7), JokerKind::Consider).unwrap();
+        assert_eq!(day07::calculate(&hands, TiePolicy::InputOrder), Ok(5905));
+
+        let text = &inputs(8)[1].text;
+        assert_eq!(day08::part2(&day08::parse(text).unwrap()), Ok(6));
+        assert_eq!(day09::part1(&day09::parse::<i64>(&first(9)).unwrap()), 114);
+    }
+
+    #[test]
+    fn test_large_inputs_are_solvable() {
+        let large = |day| inputs(day).pop().unwrap().text;
+
+        let text = large(1);
+        assert!(day01::part1(&text).is_ok());
+        assert!(day01::part2(&text).is_ok());
+
+        let games = day02::parse(&large(2)).unwrap();
+        day02::part1(&games);
+        day02::part2(&games);
+
+        let board = day03::parse(&large(3)).unwrap();
+        day03::part1(&board);
+        day03::part2(&board);
+
+        let cards = day04::parse(&large(4)).unwrap();
+        day04::part1(&cards);
+        day04::part2(&cards);
+
+        let almanac = day05::Almanac::parse(&large(5)).unwrap();
+        assert!(day05::part1(&almanac).is_ok());
+        assert!(day05::part2(&almanac).is_ok());
+
+        let races = day06::parse_input(&large(6)).unwrap();
+        assert!(day06::part1(&races, &BoatModel::default()).unwrap() > 0);
+        assert!(day06::part2(&races, &BoatModel::default()).is_ok());
+
+        for joker_kind in [JokerKind::Ignore, JokerKind::Consider] {
+            let hands = day07::parse_input(&large(7), joker_kind).unwrap();
+            assert!(day07::calculate(&hands, TiePolicy::InputOrder).is_ok());
+        }
+
+        let text = large(8);
+        let nav = day08::parse(&text).unwrap();
+        assert!(day08::part1(&nav).is_ok());
+        assert!(day08::part2(&nav).is_ok());
+
+        let histories = day09::parse::<i64>(&large(9)).unwrap();
+        assert!(day09::check(&histories).is_empty());
+        day09::part1(&histories);
+        day09::part2(&histories);
+    }
+}
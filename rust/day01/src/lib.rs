@@ -0,0 +1,137 @@
+use aoc_utils::{solution, Answer, LineError, Solution};
+
+/// A line without a digit has no calibration value.
+fn no_digit(idx: usize, line: &str) -> LineError {
+    LineError {
+        line: idx + 1,
+        value: line.to_string(),
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, LineError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let mut filtered_digits = line.chars().filter_map(|c| c.to_digit(10));
+
+            let head = filtered_digits.next().ok_or_else(|| no_digit(idx, line))?;
+            let tail = filtered_digits.next_back().unwrap_or(head);
+
+            Ok(head * 10 + tail)
+        })
+        .sum()
+}
+
+pub fn part2(input: &str) -> Result<u32, LineError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let mut head = None;
+            let mut tail = None;
+
+            for i in 0..line.len() {
+                let n = line
+                    .chars()
+                    .nth(i)
+                    .and_then(|n| n.to_digit(10))
+                    .or_else(|| {
+                        let mut n = None;
+
+                        if i + 3 <= line.len() {
+                            match &line[i..i + 3] {
+                                "one" => n = Some(1),
+                                "two" => n = Some(2),
+                                "six" => n = Some(6),
+                                _ => {}
+                            }
+                        }
+
+                        if i + 4 <= line.len() {
+                            match &line[i..i + 4] {
+                                "four" => n = Some(4),
+                                "five" => n = Some(5),
+                                "nine" => n = Some(9),
+                                _ => {}
+                            }
+                        }
+
+                        if i + 5 <= line.len() {
+                            match &line[i..i + 5] {
+                                "three" => n = Some(3),
+                                "seven" => n = Some(7),
+                                "eight" => n = Some(8),
+                                _ => {}
+                            }
+                        }
+
+                        n
+                    });
+
+                if n.is_some() {
+                    if head.is_none() {
+                        head = n;
+                    } else {
+                        tail = n;
+                    }
+                }
+            }
+
+            let head = head.ok_or_else(|| no_digit(idx, line))?;
+
+            Ok(head * 10 + tail.unwrap_or(head))
+        })
+        .sum()
+}
+
+/// Day 1 answers straight from the text, so its input is left as it is.
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> solution::Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part2(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_digit() {
+        let error = |line: usize, value: &str| LineError {
+            line,
+            value: value.to_string(),
+        };
+
+        assert_eq!(part1("1abc2\nabc"), Err(error(2, "abc")));
+        assert_eq!(
+            part1("two1nine\neightwothree"),
+            Err(error(2, "eightwothree"))
+        );
+        assert_eq!(part2("two1nine\neightwothree"), Ok(29 + 83));
+        assert_eq!(part2("abc"), Err(error(1, "abc")));
+    }
+}
+
+#[cfg(test)]
+mod fixtures {
+    use super::*;
+
+    fn solve(part: u8, input: &str) -> Answer {
+        solution::solve::<Day01>(part, input).unwrap()
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use aoc_utils::{solution, Answer, LineError, Solution};
+use lazy_regex::regex;
+
+pub type GameID = u32;
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: GameID,
+    pub r: u32,
+    pub g: u32,
+    pub b: u32,
+}
+
+impl Game {
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            r: 0,
+            g: 0,
+            b: 0,
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<HashMap<GameID, Game>, LineError> {
+    let mut games = HashMap::new();
+
+    let r = regex!(r"Game (\d+): (.+)");
+    let cubes_regex = regex!(r"(\d+) (red|blue|green)");
+
+    for (idx, line) in input.lines().enumerate() {
+        let invalid = |value: &str| LineError {
+            line: idx + 1,
+            value: value.to_string(),
+        };
+        let matched = r.captures(line).ok_or_else(|| invalid(line))?;
+
+        let id = matched[1]
+            .parse::<u32>()
+            .map_err(|_| invalid(&matched[1]))?;
+        let mut game = Game::new(id);
+
+        for (_, [count, color]) in cubes_regex
+            .captures_iter(matched.get(2).unwrap().as_str())
+            .map(|c| c.extract())
+        {
+            let count = count.parse::<u32>().map_err(|_| invalid(count))?;
+
+            match color {
+                "red" => {
+                    game.r = count.max(game.r);
+                }
+                "green" => {
+                    game.g = count.max(game.g);
+                }
+                "blue" => {
+                    game.b = count.max(game.b);
+                }
+                _ => panic!("Unknown color: {}", color),
+            }
+        }
+
+        games.insert(id, game);
+    }
+
+    Ok(games)
+}
+
+pub fn part1(games: &HashMap<GameID, Game>) -> u32 {
+    games
+        .iter()
+        .filter_map(|(id, game)| {
+            if game.r <= 12 && game.g <= 13 && game.b <= 14 {
+                Some(id)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+pub fn part2(games: &HashMap<GameID, Game>) -> u32 {
+    games.values().map(|game| game.r * game.g * game.b).sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = HashMap<GameID, Game>;
+
+    fn parse(input: &str) -> solution::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(games: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part1(games))
+    }
+
+    fn part2(games: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part2(games))
+    }
+}
+
+#[cfg(test)]
+mod fixtures {
+    use super::*;
+
+    fn solve(part: u8, input: &str) -> Answer {
+        solution::solve::<Day02>(part, input).unwrap()
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
@@ -0,0 +1,206 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use aoc_utils::{solution, Answer, Solution};
+
+pub type Coordinate = (usize, usize);
+
+#[derive(Debug)]
+pub struct Number {
+    pub value: u32,
+    pub is_part: bool,
+}
+
+#[derive(Debug)]
+pub struct Board {
+    pub numbers: Vec<Number>,
+    pub ratios: HashMap<Coordinate, Vec<u32>>,
+}
+
+/// Why the board could not be parsed, with lines numbered from 1.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// Longer than the first line, which sets the width of the board.
+    TooWide { line: usize, width: usize },
+    /// A part number which does not fit in a `u32`.
+    Overflow { line: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::TooWide { line, width } => {
+                write!(f, "line {}: wider than the board's {} columns", line, width)
+            }
+            ParseError::Overflow { line } => write!(f, "line {}: number overflows u32", line),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+pub fn parse(input: &str) -> Result<Board, ParseError> {
+    let mut numbers = Vec::new();
+    let mut ratios = HashMap::new();
+    let mut symbols = HashMap::new();
+    let height = input.lines().count();
+    let width = input.lines().next().map(|l| l.trim().len()).unwrap_or(0);
+
+    let mut raw_board = vec![vec!['.'; width]; height];
+
+    for (y, line) in input.lines().enumerate() {
+        let line = line.trim();
+
+        if line.chars().count() > width {
+            return Err(ParseError::TooWide { line: y + 1, width });
+        }
+
+        for (x, c) in line.chars().enumerate() {
+            raw_board[y][x] = c;
+
+            if is_symbol(c) {
+                symbols.insert((x, y), c);
+            }
+        }
+    }
+
+    for y in 0..height {
+        let mut curr_num: Option<(u32, bool, HashSet<Coordinate>)> = None;
+
+        for x in 0..width {
+            let coord = (x, y);
+            let c = raw_board[y][x];
+
+            if let Some(n) = c.to_digit(10) {
+                let siblings = generate_siblings(&coord, &(width, height));
+                let is_part = siblings.iter().any(|coord| symbols.contains_key(coord));
+
+                let ratios = siblings
+                    .iter()
+                    .filter_map(|(sx, sy)| {
+                        if raw_board[*sy][*sx] == '*' {
+                            Some((*sx, *sy))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<HashSet<_>>();
+
+                match curr_num {
+                    Some((p, prev_b, prev_r)) => {
+                        let value = p
+                            .checked_mul(10)
+                            .and_then(|p| p.checked_add(n))
+                            .ok_or(ParseError::Overflow { line: y + 1 })?;
+
+                        curr_num = Some((
+                            value,
+                            prev_b || is_part,
+                            prev_r.union(&ratios).cloned().collect(),
+                        ));
+                    }
+                    None => {
+                        curr_num = Some((n, is_part, ratios));
+                    }
+                }
+            }
+
+            if !c.is_ascii_digit() || x == width - 1 {
+                if let Some((value, is_part, rs)) = curr_num {
+                    numbers.push(Number { value, is_part });
+
+                    for coord in rs {
+                        ratios.entry(coord).or_insert(Vec::new()).push(value);
+                    }
+
+                    curr_num = None;
+                }
+            }
+        }
+    }
+
+    Ok(Board { numbers, ratios })
+}
+
+pub fn part1(board: &Board) -> u32 {
+    board
+        .numbers
+        .iter()
+        .filter_map(|num| if num.is_part { Some(num.value) } else { None })
+        .sum()
+}
+
+pub fn part2(board: &Board) -> u32 {
+    board
+        .ratios
+        .values()
+        .filter_map(|rs| {
+            if rs.len() == 2 {
+                Some(rs[0] * rs[1])
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Board;
+
+    fn parse(input: &str) -> solution::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(board: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part1(board))
+    }
+
+    fn part2(board: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part2(board))
+    }
+}
+
+pub fn generate_siblings(coord: &Coordinate, boundary: &Coordinate) -> Vec<Coordinate> {
+    let (x, y) = *coord;
+    let (bx, by) = *boundary;
+
+    let x = x as isize;
+    let y = y as isize;
+
+    [(x + 1, y),
+        (x + 1, y + 1),
+        (x + 1, y - 1),
+        (x, y + 1),
+        (x, y - 1),
+        (x - 1, y),
+        (x - 1, y + 1),
+        (x - 1, y - 1)]
+    .iter()
+    .filter_map(|(x, y)| {
+        if 0 <= *x && (*x as usize) < bx && 0 <= *y && (*y as usize) < by {
+            Some((*x as usize, *y as usize))
+        } else {
+            None
+        }
+    })
+    .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod fixtures {
+    use super::*;
+
+    fn solve(part: u8, input: &str) -> Answer {
+        solution::solve::<Day03>(part, input).unwrap()
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
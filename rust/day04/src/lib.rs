@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+
+use aoc_utils::{solution, Answer, LineError, Solution};
+
+#[derive(Debug)]
+pub struct Card {
+    pub winning: HashSet<u32>,
+    pub holds: HashSet<u32>,
+}
+
+impl Card {
+    fn new() -> Self {
+        Self {
+            winning: HashSet::new(),
+            holds: HashSet::new(),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Card>, LineError> {
+    let mut cards = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let invalid = |value: &str| LineError {
+            line: idx + 1,
+            value: value.to_string(),
+        };
+        let numbers = |nums: &str| {
+            nums.split_whitespace()
+                .map(|n| n.parse::<u32>().map_err(|_| invalid(n)))
+                .collect::<Result<HashSet<_>, _>>()
+        };
+
+        let mut card = Card::new();
+
+        let (winning, holds) = line
+            .split_once(": ")
+            .and_then(|(_, nums)| nums.split_once(" | "))
+            .ok_or_else(|| invalid(line))?;
+
+        card.winning = numbers(winning)?;
+        card.holds = numbers(holds)?;
+
+        cards.push(card);
+    }
+
+    Ok(cards)
+}
+
+pub fn part1(cards: &[Card]) -> u32 {
+    cards
+        .iter()
+        .map(|card| {
+            let n = card.winning.intersection(&card.holds).count() as u32;
+
+            if n == 0 {
+                0
+            } else {
+                2_u32.pow(n - 1)
+            }
+        })
+        .sum()
+}
+
+pub fn part2(cards: &[Card]) -> u32 {
+    let boundary = cards.len();
+    let mut ans = vec![1; boundary];
+
+    for (id, card) in cards.iter().enumerate() {
+        let n = card.winning.intersection(&card.holds).count();
+
+        for i in (id + 1)..=(id + n) {
+            ans[i] += ans[id];
+        }
+    }
+
+    ans.iter().sum()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> solution::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(cards: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part1(cards))
+    }
+
+    fn part2(cards: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part2(cards))
+    }
+}
+
+#[cfg(test)]
+mod fixtures {
+    use super::*;
+
+    fn solve(part: u8, input: &str) -> Answer {
+        solution::solve::<Day04>(part, input).unwrap()
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
@@ -0,0 +1,901 @@
+use std::{collections::HashMap, fmt, ops::Range};
+
+use aoc_utils::{solution, Answer, Solution};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+pub const SEED: &str = "seed";
+pub const LOCATION: &str = "location";
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub category_maps: HashMap<String, CategoryLayer>,
+}
+
+impl Almanac {
+    pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+        let mut groups = input.split("\n\n");
+        let seeds = groups
+            .next()
+            .and_then(|line| line.trim().strip_prefix("seeds:"))
+            .ok_or(ParseError::MissingSeeds)?
+            .split_whitespace()
+            .map(parse_number)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Part 2 reads the seeds as ranges, whose ends must fit in a `u64`.
+        if let Some(xs) = seeds
+            .chunks_exact(2)
+            .find(|xs| xs[0].checked_add(xs[1]).is_none())
+        {
+            return Err(ParseError::SeedRangeOverflow {
+                start: xs[0],
+                length: xs[1],
+            });
+        }
+
+        let mut category_maps = HashMap::new();
+
+        for group in groups {
+            let mut vecs = Vec::new();
+
+            let mut lines = group.trim().split('\n');
+            let (from, to) = parse_header(lines.next().unwrap())?;
+
+            for map in lines {
+                let nums = map
+                    .split_whitespace()
+                    .map(parse_number)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let [dest, source, length] = nums[..] else {
+                    return Err(ParseError::InvalidMap(map.trim().to_string()));
+                };
+
+                vecs.push(CategoryMap {
+                    source,
+                    dest,
+                    length,
+                });
+            }
+
+            if category_maps.contains_key(&from) {
+                return Err(ParseError::DuplicateMap(from));
+            }
+
+            category_maps.insert(
+                from,
+                CategoryLayer {
+                    dest: to,
+                    maps: vecs,
+                },
+            );
+        }
+
+        Ok(Almanac {
+            seeds,
+            category_maps,
+        })
+    }
+
+    /// Follows the `X-to-Y` links from `source` until `dest` is reached.
+    pub fn chain(&self, source: &str, dest: &str) -> Result<Vec<&CategoryLayer>, ChainError> {
+        let mut chain = Vec::new();
+        let mut visited = vec![source];
+        let mut current = source;
+
+        while current != dest {
+            let layer = self
+                .category_maps
+                .get(current)
+                .ok_or_else(|| ChainError::MissingLink(current.to_string()))?;
+
+            if visited.contains(&layer.dest.as_str()) {
+                let mut cycle = visited.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+                cycle.push(layer.dest.clone());
+                return Err(ChainError::Cycle(cycle));
+            }
+
+            chain.push(layer);
+            visited.push(&layer.dest);
+            current = &layer.dest;
+        }
+
+        Ok(chain)
+    }
+
+    pub fn convert(&self, source: &str, dest: &str, value: u64) -> Result<u64, ChainError> {
+        Ok(find_lowest_location(&self.chain(source, dest)?, &value))
+    }
+
+    pub fn flatten(&self, source: &str, dest: &str) -> Result<FlatMap, ChainError> {
+        Ok(FlatMap::compile(&self.chain(source, dest)?))
+    }
+
+    pub fn seeds_for_location(&self, location: u64) -> Result<Vec<u64>, ChainError> {
+        Ok(find_seeds(&self.chain(SEED, LOCATION)?, location))
+    }
+
+    /// Reports overlapping, empty and overflowing maps without changing the
+    /// almanac. `Strict` rejects it on any issue; `Lenient` only on maps
+    /// which overflow, as they cannot be evaluated, and returns the other
+    /// issues as warnings.
+    pub fn validate(&self, validation: Validation) -> Result<Vec<MapIssue>, Vec<MapIssue>> {
+        let mut issues = Vec::new();
+        let mut categories = self.category_maps.keys().collect::<Vec<_>>();
+        categories.sort();
+
+        for category in categories {
+            let layer = &self.category_maps[category];
+            let name = format!("{}-to-{}", category, layer.dest);
+
+            for (idx, map) in layer.maps.iter().enumerate() {
+                if map.length == 0 {
+                    issues.push(MapIssue::ZeroLength {
+                        layer: name.clone(),
+                        index: idx,
+                    });
+                } else if map.source.checked_add(map.length - 1).is_none()
+                    || map.dest.checked_add(map.length - 1).is_none()
+                {
+                    issues.push(MapIssue::Overflow {
+                        layer: name.clone(),
+                        index: idx,
+                    });
+                }
+            }
+
+            for (i, a) in layer.maps.iter().enumerate() {
+                for (j, b) in layer.maps.iter().enumerate().skip(i + 1) {
+                    if a.overlaps(b) {
+                        issues.push(MapIssue::Overlap {
+                            layer: name.clone(),
+                            first: i,
+                            second: j,
+                        });
+                    }
+                }
+            }
+        }
+
+        let fatal = match validation {
+            Validation::Strict => !issues.is_empty(),
+            Validation::Lenient => issues
+                .iter()
+                .any(|issue| matches!(issue, MapIssue::Overflow { .. })),
+        };
+
+        if fatal {
+            Err(issues)
+        } else {
+            Ok(issues)
+        }
+    }
+
+    pub fn seed_ranges(&self) -> Vec<Range<u64>> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|xs| xs[0]..(xs[0] + xs[1]))
+            .collect()
+    }
+}
+
+pub fn parse_header(line: &str) -> Result<(String, String), ParseError> {
+    let name = line.trim().trim_end_matches(" map:");
+    let (source, dest) = name
+        .split_once("-to-")
+        .ok_or_else(|| ParseError::InvalidHeader(line.trim().to_string()))?;
+
+    Ok((source.to_string(), dest.to_string()))
+}
+
+fn parse_number(s: &str) -> Result<u64, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError::InvalidNumber(s.to_string()))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingSeeds,
+    InvalidNumber(String),
+    InvalidHeader(String),
+    /// A map line without exactly a destination, source and length.
+    InvalidMap(String),
+    DuplicateMap(String),
+    SeedRangeOverflow {
+        start: u64,
+        length: u64,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingSeeds => write!(f, "missing `seeds:` line"),
+            ParseError::InvalidNumber(s) => write!(f, "invalid number `{}`", s),
+            ParseError::InvalidHeader(line) => write!(f, "invalid map header `{}`", line),
+            ParseError::InvalidMap(line) => write!(f, "invalid map `{}`", line),
+            ParseError::DuplicateMap(category) => {
+                write!(f, "more than one map from category `{}`", category)
+            }
+            ParseError::SeedRangeOverflow { start, length } => write!(
+                f,
+                "seed range of {} from {} ends past u64::MAX",
+                length, start
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChainError {
+    MissingLink(String),
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainError::MissingLink(category) => {
+                write!(f, "no map converts from category `{}`", category)
+            }
+            ChainError::Cycle(categories) => {
+                write!(f, "cyclic category maps: {}", categories.join(" -> "))
+            }
+        }
+    }
+}
+
+/// Why a part has no answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Chain(ChainError),
+    NoSeeds,
+}
+
+impl From<ChainError> for SolveError {
+    fn from(e: ChainError) -> Self {
+        SolveError::Chain(e)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Chain(e) => write!(f, "{}", e),
+            SolveError::NoSeeds => write!(f, "there are no seeds to plant"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+#[derive(Debug)]
+pub struct CategoryLayer {
+    pub dest: String,
+    pub maps: Vec<CategoryMap>,
+}
+
+impl CategoryLayer {
+    pub fn convert(&self, value: u64) -> u64 {
+        self.maps
+            .iter()
+            .find_map(|map| map.convert(value))
+            .unwrap_or(value)
+    }
+
+    /// Returns every value this layer converts into `value`, including
+    /// `value` itself when no map covers it.
+    pub fn invert(&self, value: u64) -> Vec<u64> {
+        let mut sources = self
+            .maps
+            .iter()
+            .filter_map(|map| map.invert(value))
+            .collect::<Vec<_>>();
+        sources.push(value);
+
+        // A map listed earlier wins on overlapping source ranges, so only
+        // keep the candidates which actually convert back into `value`.
+        sources.retain(|source| self.convert(*source) == value);
+        sources
+    }
+}
+
+#[derive(Debug)]
+pub struct CategoryMap {
+    pub source: u64,
+    pub dest: u64,
+    pub length: u64,
+}
+
+impl CategoryMap {
+    pub fn convert(&self, target: u64) -> Option<u64> {
+        // Measured from the start of the range, so a range which ends at
+        // `u64::MAX` needs no bound past it.
+        let distance = target.checked_sub(self.source)?;
+
+        if distance >= self.length {
+            return None;
+        }
+
+        Some(self.dest + distance)
+    }
+
+    pub fn overlaps(&self, other: &CategoryMap) -> bool {
+        if self.length == 0 || other.length == 0 {
+            return false;
+        }
+
+        let last = self.source.saturating_add(self.length - 1);
+        let other_last = other.source.saturating_add(other.length - 1);
+
+        self.source <= other_last && other.source <= last
+    }
+
+    pub fn invert(&self, target: u64) -> Option<u64> {
+        let distance = target.checked_sub(self.dest)?;
+
+        if distance >= self.length {
+            return None;
+        }
+
+        Some(self.source + distance)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Validation {
+    Strict,
+    Lenient,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MapIssue {
+    Overlap {
+        layer: String,
+        first: usize,
+        second: usize,
+    },
+    ZeroLength {
+        layer: String,
+        index: usize,
+    },
+    Overflow {
+        layer: String,
+        index: usize,
+    },
+}
+
+impl fmt::Display for MapIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapIssue::Overlap {
+                layer,
+                first,
+                second,
+            } => write!(
+                f,
+                "`{}`: source ranges of maps #{} and #{} overlap",
+                layer, first, second
+            ),
+            MapIssue::ZeroLength { layer, index } => {
+                write!(f, "`{}`: map #{} has zero length", layer, index)
+            }
+            MapIssue::Overflow { layer, index } => {
+                write!(f, "`{}`: map #{} overflows u64", layer, index)
+            }
+        }
+    }
+}
+
+/// Values in `start..=last` are shifted by `offset`. The range is inclusive
+/// so a segment can reach `u64::MAX`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub start: u64,
+    pub last: u64,
+    pub offset: i128,
+}
+
+impl Segment {
+    pub fn apply(&self, value: u64) -> u64 {
+        (value as i128 + self.offset) as u64
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}..={} -> {}..={} ({:+})",
+            self.start,
+            self.last,
+            self.start as i128 + self.offset,
+            self.last as i128 + self.offset,
+            self.offset
+        )
+    }
+}
+
+/// A whole chain of layers composed into one piecewise-linear function. The
+/// segments are sorted, contiguous and cover `0..=u64::MAX`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FlatMap {
+    pub segments: Vec<Segment>,
+}
+
+impl FlatMap {
+    pub fn identity() -> FlatMap {
+        FlatMap {
+            segments: vec![Segment {
+                start: 0,
+                last: u64::MAX,
+                offset: 0,
+            }],
+        }
+    }
+
+    pub fn compile(chain: &[&CategoryLayer]) -> FlatMap {
+        chain.iter().fold(FlatMap::identity(), |flat, layer| {
+            flat.compose(&FlatMap::from_layer(layer))
+        })
+    }
+
+    pub fn from_layer(layer: &CategoryLayer) -> FlatMap {
+        // Where segments start: at the start of each map and just past its
+        // end, unless that is past `u64::MAX`.
+        let mut starts = vec![0];
+
+        for map in &layer.maps {
+            starts.push(map.source);
+            starts.extend(map.source.checked_add(map.length));
+        }
+
+        starts.sort();
+        starts.dedup();
+
+        let segments = starts
+            .iter()
+            .enumerate()
+            .map(|(i, &start)| Segment {
+                start,
+                last: starts.get(i + 1).map_or(u64::MAX, |next| next - 1),
+                offset: layer.convert(start) as i128 - start as i128,
+            })
+            .collect();
+
+        FlatMap::merged(segments)
+    }
+
+    /// Applies `self` first and then `next`.
+    pub fn compose(&self, next: &FlatMap) -> FlatMap {
+        let mut segments = Vec::new();
+
+        for segment in &self.segments {
+            let mut start = segment.start;
+
+            loop {
+                let image = segment.apply(start);
+                let next_segment = next
+                    .find(image)
+                    .expect("value outside of the flattened domain");
+                let last = start + (next_segment.last - image).min(segment.last - start);
+
+                segments.push(Segment {
+                    start,
+                    last,
+                    offset: segment.offset + next_segment.offset,
+                });
+
+                if last == segment.last {
+                    break;
+                }
+
+                start = last + 1;
+            }
+        }
+
+        FlatMap::merged(segments)
+    }
+
+    pub fn merged(segments: Vec<Segment>) -> FlatMap {
+        let mut merged: Vec<Segment> = Vec::new();
+
+        for segment in segments {
+            match merged.last_mut() {
+                Some(last) if last.last + 1 == segment.start && last.offset == segment.offset => {
+                    last.last = segment.last;
+                }
+                _ => merged.push(segment),
+            }
+        }
+
+        FlatMap { segments: merged }
+    }
+
+    pub fn find(&self, value: u64) -> Option<&Segment> {
+        let idx = self.segments.partition_point(|s| s.last < value);
+        self.segments.get(idx)
+    }
+
+    pub fn convert(&self, value: u64) -> u64 {
+        self.find(value).map_or(value, |s| s.apply(value))
+    }
+}
+
+pub fn part1(almanac: &Almanac) -> Result<u64, SolveError> {
+    let flat = almanac.flatten(SEED, LOCATION)?;
+
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| flat.convert(*seed))
+        .min()
+        .ok_or(SolveError::NoSeeds)
+}
+
+pub fn part2(almanac: &Almanac) -> Result<u64, SolveError> {
+    let flat = almanac.flatten(SEED, LOCATION)?;
+
+    almanac
+        .seed_ranges()
+        .into_par_iter()
+        .flatten()
+        .map(|seed| flat.convert(seed))
+        .min()
+        .ok_or(SolveError::NoSeeds)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Almanac;
+
+    /// Also rejects maps which overflow, as they cannot be evaluated.
+    fn parse(input: &str) -> solution::Result<Self::Input<'_>> {
+        let almanac = Almanac::parse(input)?;
+
+        if let Err(issues) = almanac.validate(Validation::Lenient) {
+            let issues = issues.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+            return Err(issues.join("; ").into());
+        }
+
+        Ok(almanac)
+    }
+
+    fn part1(almanac: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part1(almanac)?)
+    }
+
+    fn part2(almanac: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part2(almanac)?)
+    }
+}
+
+pub fn find_lowest_location(chain: &[&CategoryLayer], seed: &u64) -> u64 {
+    chain
+        .iter()
+        .fold(*seed, |current_seed, layer| layer.convert(current_seed))
+}
+
+pub fn find_seeds(chain: &[&CategoryLayer], location: u64) -> Vec<u64> {
+    let mut seeds = chain.iter().rev().fold(vec![location], |values, layer| {
+        values
+            .into_iter()
+            .flat_map(|value| layer.invert(value))
+            .collect()
+    });
+
+    seeds.sort();
+    seeds.dedup();
+    seeds
+}
+
+/// Walks the locations upward and stops at the first one whose seeds fall
+/// within any of the seed ranges. Agrees with `part2` without scanning seeds.
+///
+/// The location of the first seed of any range is itself an answer, so the
+/// walk never goes past the lowest of those. There is nothing to find
+/// without seed ranges.
+pub fn reverse_search(almanac: &Almanac) -> Result<Option<u64>, ChainError> {
+    let chain = almanac.chain(SEED, LOCATION)?;
+    let ranges = almanac.seed_ranges();
+    let bound = match ranges
+        .iter()
+        .filter(|range| !range.is_empty())
+        .map(|range| find_lowest_location(&chain, &range.start))
+        .min()
+    {
+        Some(bound) => bound,
+        None => return Ok(None),
+    };
+
+    Ok((0..=bound).find(|location| {
+        find_seeds(&chain, *location)
+            .iter()
+            .any(|seed| ranges.iter().any(|range| range.contains(seed)))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = include_str!("../../tests/fixtures/day05/example1.txt");
+
+    #[test]
+    fn test_reverse_search() {
+        let almanac = Almanac::parse(INPUT).unwrap();
+        assert_eq!(reverse_search(&almanac), Ok(part2(&almanac).ok()));
+
+        // Seed 5 maps to 105, which no seed range inverts into before it.
+        let almanac = Almanac::parse(
+            "seeds: 5 1
+
+            seed-to-location map:
+            100 0 10",
+        )
+        .unwrap();
+        assert_eq!(reverse_search(&almanac), Ok(Some(105)));
+
+        let almanac = Almanac::parse(
+            "seeds: 5
+
+            seed-to-location map:
+            100 0 10",
+        )
+        .unwrap();
+        assert_eq!(reverse_search(&almanac), Ok(None));
+    }
+
+    #[test]
+    fn test_seeds_for_location() {
+        let almanac = Almanac::parse(INPUT).unwrap();
+        assert_eq!(almanac.seeds_for_location(46), Ok(vec![82]));
+
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+        for seed in 0..120 {
+            let location = find_lowest_location(&chain, &seed);
+            assert!(find_seeds(&chain, location).contains(&seed));
+        }
+    }
+
+    #[test]
+    fn test_flatten() {
+        let almanac = Almanac::parse(INPUT).unwrap();
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+        let flat = FlatMap::compile(&chain);
+
+        assert_eq!(flat.segments.first().unwrap().start, 0);
+        assert_eq!(flat.segments.last().unwrap().last, u64::MAX);
+
+        for xs in flat.segments.windows(2) {
+            assert_eq!(xs[0].last + 1, xs[1].start);
+            assert_ne!(xs[0].offset, xs[1].offset);
+        }
+
+        for seed in (0..120).chain([u64::MAX - 1, u64::MAX]) {
+            assert_eq!(flat.convert(seed), find_lowest_location(&chain, &seed));
+        }
+    }
+
+    #[test]
+    fn test_flatten_covers_u64_max() {
+        // The first map sends 10 up to `u64::MAX`, which the second layer
+        // must still find a segment for.
+        let almanac = Almanac::parse(
+            "seeds: 1
+
+            seed-to-soil map:
+            18446744073709551615 10 1
+
+            soil-to-location map:
+            0 18446744073709551615 1",
+        )
+        .unwrap();
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+        let flat = FlatMap::compile(&chain);
+
+        assert_eq!(flat.segments.last().unwrap().last, u64::MAX);
+        assert_eq!(flat.convert(10), 0);
+        assert_eq!(flat.convert(u64::MAX), 0);
+
+        for seed in [0, 9, 10, 11, u64::MAX - 1, u64::MAX] {
+            assert_eq!(flat.convert(seed), find_lowest_location(&chain, &seed));
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let almanac = Almanac::parse(INPUT).unwrap();
+        assert_eq!(almanac.validate(Validation::Strict), Ok(vec![]));
+
+        const INVALID: &str = "seeds: 1 2
+
+            seed-to-soil map:
+            50 98 2
+            52 50 49
+            0 10 0
+
+            soil-to-location map:
+            18446744073709551615 0 2";
+
+        let mut issues = vec![
+            MapIssue::ZeroLength {
+                layer: "seed-to-soil".to_string(),
+                index: 2,
+            },
+            MapIssue::Overlap {
+                layer: "seed-to-soil".to_string(),
+                first: 0,
+                second: 1,
+            },
+            MapIssue::Overflow {
+                layer: "soil-to-location".to_string(),
+                index: 0,
+            },
+        ];
+
+        let almanac = Almanac::parse(INVALID).unwrap();
+        assert_eq!(almanac.validate(Validation::Strict).unwrap_err(), issues);
+        assert_eq!(almanac.validate(Validation::Lenient).unwrap_err().len(), 3);
+        assert_eq!(almanac.category_maps["soil"].maps.len(), 1);
+
+        // Without the overflowing map, lenient validation only warns and
+        // leaves the maps as they were.
+        let almanac = Almanac::parse(
+            INVALID
+                .replace("18446744073709551615 0 2", "0 0 2")
+                .as_str(),
+        )
+        .unwrap();
+        issues.pop();
+        assert_eq!(almanac.validate(Validation::Lenient), Ok(issues));
+        assert_eq!(almanac.category_maps["seed"].maps.len(), 3);
+
+        // The last image of this map is `u64::MAX`, which still fits.
+        let almanac = Almanac::parse(
+            "seeds: 1
+
+            seed-to-location map:
+            18446744073709551614 0 2",
+        )
+        .unwrap();
+        assert_eq!(almanac.validate(Validation::Strict), Ok(vec![]));
+        assert_eq!(almanac.convert(SEED, LOCATION, 1), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |input: &str| Almanac::parse(input).unwrap_err();
+
+        assert_eq!(parse(""), ParseError::MissingSeeds);
+        assert_eq!(
+            parse("seeds: 1 x"),
+            ParseError::InvalidNumber("x".to_string())
+        );
+        assert_eq!(
+            parse("seeds: 1\n\nseed map:\n1 2 3"),
+            ParseError::InvalidHeader("seed map:".to_string())
+        );
+        assert_eq!(
+            parse("seeds: 18446744073709551615 5"),
+            ParseError::SeedRangeOverflow {
+                start: u64::MAX,
+                length: 5
+            }
+        );
+        assert_eq!(
+            parse("seeds: 1\n\nseed-to-soil map:\n1 2"),
+            ParseError::InvalidMap("1 2".to_string())
+        );
+        assert_eq!(
+            parse("seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nseed-to-soil map:\n4 5 6"),
+            ParseError::DuplicateMap("seed".to_string())
+        );
+
+        assert!(Day05::parse("seeds: 1\n\nseed-to-location map:\n0 1 0").is_ok());
+        assert_eq!(
+            Day05::parse("seeds: 1\n\nseed-to-location map:\n18446744073709551615 0 2")
+                .unwrap_err()
+                .to_string(),
+            "`seed-to-location`: map #0 overflows u64"
+        );
+    }
+
+    #[test]
+    fn test_maps_in_any_order() {
+        let mut groups = INPUT.split("\n\n").collect::<Vec<_>>();
+        groups[1..].reverse();
+
+        let almanac = Almanac::parse(&groups.join("\n\n")).unwrap();
+        assert_eq!(part1(&almanac), Ok(35));
+        assert_eq!(part2(&almanac), Ok(46));
+    }
+
+    #[test]
+    fn test_map_excludes_range_end() {
+        // `50 98 2` covers sources 98 and 99 only; 100 is left unmapped.
+        let map = CategoryMap {
+            source: 98,
+            dest: 50,
+            length: 2,
+        };
+
+        assert_eq!(map.convert(97), None);
+        assert_eq!(map.convert(98), Some(50));
+        assert_eq!(map.convert(99), Some(51));
+        assert_eq!(map.convert(100), None);
+    }
+
+    #[test]
+    fn test_convert_between_categories() {
+        let almanac = Almanac::parse(INPUT).unwrap();
+        assert_eq!(almanac.convert("seed", "soil", 79), Ok(81));
+        assert_eq!(almanac.convert("soil", "humidity", 81), Ok(78));
+        assert_eq!(almanac.convert("water", "water", 81), Ok(81));
+    }
+
+    #[test]
+    fn test_chain_errors() {
+        let almanac = Almanac::parse(INPUT).unwrap();
+        assert_eq!(
+            almanac.convert("location", "seed", 82),
+            Err(ChainError::MissingLink("location".to_string()))
+        );
+
+        let almanac = Almanac::parse(
+            "seeds: 1
+
+            a-to-b map:
+            0 0 1
+
+            b-to-a map:
+            0 0 1",
+        )
+        .unwrap();
+        assert_eq!(
+            almanac.chain("a", "c").map(|chain| chain.len()),
+            Err(ChainError::Cycle(vec![
+                "a".to_string(),
+                "b".to_string(),
+                "a".to_string()
+            ]))
+        );
+
+        let almanac = Almanac::parse(
+            "seeds: 1 2
+
+            seed-to-soil map:
+            0 0 1",
+        )
+        .unwrap();
+        let missing = ChainError::MissingLink("soil".to_string());
+        assert_eq!(part1(&almanac), Err(SolveError::Chain(missing)));
+        assert!(part2(&almanac).is_err());
+        assert!(reverse_search(&almanac).is_err());
+
+        let almanac = Almanac::parse(
+            "seeds: 3 0
+
+            seed-to-location map:
+            0 0 1",
+        )
+        .unwrap();
+        assert_eq!(part2(&almanac), Err(SolveError::NoSeeds));
+        assert_eq!(reverse_search(&almanac), Ok(None));
+    }
+}
+
+#[cfg(test)]
+mod fixtures {
+    use super::*;
+
+    fn solve(part: u8, input: &str) -> Answer {
+        solution::solve::<Day05>(part, input).unwrap()
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
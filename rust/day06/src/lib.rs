@@ -0,0 +1,546 @@
+use std::fmt;
+
+use aoc_utils::{solution, Answer, Solution};
+use serde_json::Value;
+
+pub type Race = (u64, u64);
+
+/// Builds a `BoatModel` from `--charge-rate`, `--max-speed`, `--curve` and
+/// `--min-hold` options, falling back to the puzzle's physics.
+pub fn parse_model(mut args: impl Iterator<Item = String>) -> Result<BoatModel, String> {
+    let mut model = BoatModel::default();
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", flag))?;
+        let number = |value: &str| {
+            value
+                .parse::<u128>()
+                .map_err(|_| format!("invalid number for {}: {}", flag, value))
+        };
+
+        match flag.as_str() {
+            "--charge-rate" => model.charge_rate = number(&value)?,
+            "--max-speed" => model.max_speed = Some(number(&value)?),
+            "--min-hold" => model.min_hold = number(&value)?,
+            "--curve" => {
+                model.curve = match value.as_str() {
+                    "linear" => Curve::Linear,
+                    "quadratic" => Curve::Quadratic,
+                    _ => return Err(format!("unknown curve: {}", value)),
+                }
+            }
+            _ => return Err(format!("unknown option: {}", flag)),
+        }
+    }
+
+    Ok(model)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingLabel(String),
+    UnknownLabel(String),
+    /// The `Time:` or `Distance:` line is not there.
+    MissingLine(&'static str),
+    LengthMismatch {
+        times: usize,
+        distances: usize,
+    },
+    InvalidNumber(String),
+    InvalidRace(String),
+    InvalidJson(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingLabel(line) => write!(f, "missing label: {}", line),
+            ParseError::UnknownLabel(label) => write!(f, "unknown label: {}", label),
+            ParseError::MissingLine(label) => write!(f, "missing `{}:` line", label),
+            ParseError::LengthMismatch { times, distances } => {
+                write!(f, "{} times but {} distances", times, distances)
+            }
+            ParseError::InvalidNumber(n) => write!(f, "invalid number: {}", n),
+            ParseError::InvalidRace(race) => write!(f, "invalid race: {}", race),
+            ParseError::InvalidJson(e) => write!(f, "invalid JSON: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_number(n: &str) -> Result<u64, ParseError> {
+    n.trim()
+        .parse()
+        .map_err(|_| ParseError::InvalidNumber(n.trim().to_string()))
+}
+
+/// Accepts the puzzle's labelled `Time:`/`Distance:` lines in any order, a
+/// CSV list of `time,distance` rows, or a JSON array of races.
+pub fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    let input = input.trim();
+
+    if input.starts_with('[') {
+        parse_json(input)
+    } else if input.contains(',') {
+        parse_csv(input)
+    } else {
+        parse_labelled(input)
+    }
+}
+
+pub fn parse_labelled(input: &str) -> Result<Vec<Race>, ParseError> {
+    let mut times = None;
+    let mut distances = None;
+
+    for line in input.lines() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let (label, nums) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::MissingLabel(line.to_string()))?;
+        let nums = nums
+            .split_whitespace()
+            .map(parse_number)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match label.trim().to_lowercase().as_str() {
+            "time" => times = Some(nums),
+            "distance" => distances = Some(nums),
+            _ => return Err(ParseError::UnknownLabel(label.to_string())),
+        }
+    }
+
+    let times = times.ok_or(ParseError::MissingLine("Time"))?;
+    let distances = distances.ok_or(ParseError::MissingLine("Distance"))?;
+
+    if times.len() != distances.len() {
+        return Err(ParseError::LengthMismatch {
+            times: times.len(),
+            distances: distances.len(),
+        });
+    }
+
+    Ok(std::iter::zip(times, distances).collect())
+}
+
+pub fn parse_csv(input: &str) -> Result<Vec<Race>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.eq_ignore_ascii_case("time,distance"))
+        .map(|line| {
+            let (time, distance) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::InvalidRace(line.to_string()))?;
+
+            Ok((parse_number(time)?, parse_number(distance)?))
+        })
+        .collect()
+}
+
+pub fn parse_json(input: &str) -> Result<Vec<Race>, ParseError> {
+    let value =
+        serde_json::from_str::<Value>(input).map_err(|e| ParseError::InvalidJson(e.to_string()))?;
+
+    value
+        .as_array()
+        .ok_or_else(|| ParseError::InvalidJson("races must be an array".to_string()))?
+        .iter()
+        .map(|race| {
+            let (time, distance) = match race {
+                Value::Array(pair) if pair.len() == 2 => (&pair[0], &pair[1]),
+                Value::Object(fields) => match (fields.get("time"), fields.get("distance")) {
+                    (Some(time), Some(distance)) => (time, distance),
+                    _ => return Err(ParseError::InvalidRace(race.to_string())),
+                },
+                _ => return Err(ParseError::InvalidRace(race.to_string())),
+            };
+            let number = |n: &Value| {
+                n.as_u64()
+                    .ok_or_else(|| ParseError::InvalidNumber(n.to_string()))
+            };
+
+            Ok((number(time)?, number(distance)?))
+        })
+        .collect()
+}
+
+/// Concatenates the digits of `nums`, as read with the spaces between them
+/// ignored. A few kerned `u64`s already overflow one, so this works in
+/// `u128` like the counting does, and is `None` when even that overflows.
+pub fn kern(nums: &[u64]) -> Option<u128> {
+    nums.iter().try_fold(0_u128, |acc, n| {
+        let n = *n as u128;
+        let mut shift = 10;
+
+        while shift <= n {
+            shift = shift.checked_mul(10)?;
+        }
+
+        acc.checked_mul(shift)?.checked_add(n)
+    })
+}
+
+/// A number on the way to an answer which does not fit in a `u128`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Overflow {
+    Product,
+    KernedTime,
+    KernedDistance,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Overflow::Product => write!(f, "the product of the counts overflows u128"),
+            Overflow::KernedTime => write!(f, "the kerned time does not fit in u128"),
+            Overflow::KernedDistance => write!(f, "the kerned distance does not fit in u128"),
+        }
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+pub fn part1(races: &[Race], model: &BoatModel) -> Result<u128, Overflow> {
+    races.iter().try_fold(1_u128, |acc, (time, distance)| {
+        acc.checked_mul(model.count_winning(*time as u128, *distance as u128))
+            .ok_or(Overflow::Product)
+    })
+}
+
+pub fn part2(races: &[Race], model: &BoatModel) -> Result<u128, Overflow> {
+    let (times, distances): (Vec<_>, Vec<_>) = races.iter().copied().unzip();
+
+    let time = kern(&times).ok_or(Overflow::KernedTime)?;
+    let distance = kern(&distances).ok_or(Overflow::KernedDistance)?;
+
+    Ok(model.count_winning(time, distance))
+}
+
+/// Solves with the puzzle's own boat, `BoatModel::default()`.
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<Race>;
+
+    fn parse(input: &str) -> solution::Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(races: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part1(races, &BoatModel::default())?)
+    }
+
+    fn part2(races: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part2(races, &BoatModel::default())?)
+    }
+}
+
+/// How the speed grows with the time the button is held.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Curve {
+    Linear,
+    Quadratic,
+}
+
+/// The physics of a boat: holding the button for `hold` ms charges it to
+/// `charge_rate * curve(hold)` mm/ms, capped at `max_speed`. Holds shorter
+/// than `min_hold` never leave the dock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoatModel {
+    pub charge_rate: u128,
+    pub max_speed: Option<u128>,
+    pub curve: Curve,
+    pub min_hold: u128,
+}
+
+impl Default for BoatModel {
+    fn default() -> Self {
+        Self {
+            charge_rate: 1,
+            max_speed: None,
+            curve: Curve::Linear,
+            min_hold: 0,
+        }
+    }
+}
+
+impl BoatModel {
+    pub fn speed(&self, hold: u128) -> u128 {
+        let charged = match self.curve {
+            Curve::Linear => hold,
+            Curve::Quadratic => hold.saturating_mul(hold),
+        };
+        let speed = charged.saturating_mul(self.charge_rate);
+
+        self.max_speed.map_or(speed, |max| speed.min(max))
+    }
+
+    pub fn distance(&self, time: u128, hold: u128) -> u128 {
+        if hold < self.min_hold || hold > time {
+            return 0;
+        }
+
+        self.speed(hold).saturating_mul(time - hold)
+    }
+
+    /// Counts the hold times which beat `distance`.
+    pub fn count_winning(&self, time: u128, distance: u128) -> u128 {
+        if self.curve == Curve::Linear && self.max_speed.is_none() {
+            self.count_quadratic(time, distance)
+        } else {
+            self.count_unimodal(time, distance)
+        }
+    }
+
+    /// `x * (time - x)` grows until `time / 2` and the winning holds are
+    /// symmetric around it, so only the shortest one has to be searched for.
+    pub fn count_quadratic(&self, time: u128, distance: u128) -> u128 {
+        let beats = |x: u128| self.speed(x).saturating_mul(time - x) > distance;
+        let mid = time / 2;
+
+        if !beats(mid) {
+            return 0;
+        }
+
+        let shortest = partition_point(0, mid, |x| !beats(x));
+        let longest = time - shortest;
+
+        (longest + 1).saturating_sub(shortest.max(self.min_hold))
+    }
+
+    /// Fallback for the other models, whose distance rises to a single peak
+    /// and then falls. The peak is found by ternary search and both edges of
+    /// the winning range by binary search.
+    pub fn count_unimodal(&self, time: u128, distance: u128) -> u128 {
+        let f = |x: u128| self.distance(time, x);
+
+        let (mut lower, mut upper) = (self.min_hold.min(time), time);
+
+        while upper - lower > 2 {
+            let m1 = lower + (upper - lower) / 3;
+            let m2 = upper - (upper - lower) / 3;
+
+            match f(m1).cmp(&f(m2)) {
+                std::cmp::Ordering::Less => lower = m1 + 1,
+                std::cmp::Ordering::Greater => upper = m2 - 1,
+                std::cmp::Ordering::Equal => (lower, upper) = (m1, m2),
+            }
+        }
+
+        let peak = (lower..=upper).max_by_key(|x| f(*x)).unwrap();
+
+        if f(peak) <= distance {
+            return 0;
+        }
+
+        // Nothing travels when the whole race is spent holding, so the last
+        // winning hold is always before `time`.
+        let first = partition_point(self.min_hold.min(peak), peak, |x| f(x) <= distance);
+        let end = partition_point(peak, time, |x| f(x) > distance);
+
+        end - first
+    }
+}
+
+/// Returns the first `x` in `lower..=upper` for which `pred` is false,
+/// assuming `pred` holds for a prefix of the range.
+pub fn partition_point<F>(lower: u128, upper: u128, pred: F) -> u128
+where
+    F: Fn(u128) -> bool,
+{
+    let (mut lower, mut upper) = (lower, upper);
+
+    while lower < upper {
+        let x = lower + (upper - lower) / 2;
+
+        if pred(x) {
+            lower = x + 1;
+        } else {
+            upper = x;
+        }
+    }
+
+    lower
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/day06/example1.txt");
+
+    #[test]
+    fn test_input_formats() {
+        let expected = vec![(7, 9), (15, 40), (30, 200)];
+
+        assert_eq!(
+            parse_input("Distance:  9  40  200\nTime:      7  15   30"),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            parse_input("time,distance\n7,9\n15,40\n30,200"),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            parse_input("[[7, 9], [15, 40], [30, 200]]"),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            parse_input(
+                r#"[{"time": 7, "distance": 9}, {"time": 15, "distance": 40},
+                    {"time": 30, "distance": 200}]"#
+            ),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_input(""), Err(ParseError::MissingLine("Time")));
+        assert_eq!(
+            parse_input("Time: 7 15\nDistance: 9"),
+            Err(ParseError::LengthMismatch {
+                times: 2,
+                distances: 1
+            })
+        );
+        assert_eq!(
+            parse_input("Time: 7\nSpeed: 9"),
+            Err(ParseError::UnknownLabel("Speed".to_string()))
+        );
+        assert_eq!(
+            parse_input("7,x"),
+            Err(ParseError::InvalidNumber("x".to_string()))
+        );
+        assert_eq!(
+            parse_input("[[7, -9]]"),
+            Err(ParseError::InvalidNumber("-9".to_string()))
+        );
+        assert!(matches!(
+            parse_input("[[7, 9]"),
+            Err(ParseError::InvalidJson(_))
+        ));
+        assert_eq!(
+            parse_input(r#"[{"time": 7}]"#),
+            Err(ParseError::InvalidRace(r#"{"time":7}"#.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_count_winning() {
+        let models = [
+            BoatModel::default(),
+            BoatModel {
+                charge_rate: 3,
+                min_hold: 4,
+                ..BoatModel::default()
+            },
+            BoatModel {
+                max_speed: Some(5),
+                ..BoatModel::default()
+            },
+            BoatModel {
+                curve: Curve::Quadratic,
+                min_hold: 2,
+                ..BoatModel::default()
+            },
+            BoatModel {
+                charge_rate: 2,
+                max_speed: Some(30),
+                curve: Curve::Quadratic,
+                min_hold: 0,
+            },
+        ];
+
+        for model in models {
+            for time in 0..40 {
+                for distance in 0..200 {
+                    let expected = (0..=time)
+                        .filter(|x| model.distance(time, *x) > distance)
+                        .count() as u128;
+
+                    assert_eq!(model.count_winning(time, distance), expected);
+                    assert_eq!(model.count_unimodal(time, distance), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_alternative_model() {
+        let input = parse_input(EXAMPLE).unwrap();
+        let model = parse_model(
+            ["--max-speed", "4", "--min-hold", "2"]
+                .iter()
+                .map(|s| s.to_string()),
+        )
+        .unwrap();
+
+        // 7ms: holds 2..=4, 15ms: hold 4, 30ms: nothing beats 200mm.
+        assert_eq!(model.count_winning(7, 9), 3);
+        assert_eq!(model.count_winning(15, 40), 1);
+        assert_eq!(part1(&input, &model), Ok(0));
+    }
+
+    #[test]
+    fn test_model_errors() {
+        let model = |args: &[&str]| parse_model(args.iter().map(|s| s.to_string()));
+
+        assert_eq!(model(&["foo"]), Err("missing value for foo".to_string()));
+        assert_eq!(
+            model(&["--min-hold", "x"]),
+            Err("invalid number for --min-hold: x".to_string())
+        );
+        assert_eq!(
+            model(&["--curve", "cubic"]),
+            Err("unknown curve: cubic".to_string())
+        );
+        assert_eq!(
+            model(&["--speed", "4"]),
+            Err("unknown option: --speed".to_string())
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let model = BoatModel::default();
+        let races = parse_input("Time: 9000000000 9000000000\nDistance: 0 0").unwrap();
+
+        assert_eq!(part1(&races, &model), Ok(8999999999 * 8999999999));
+        assert_eq!(part1(&[(9000000000, 0); 5], &model), Err(Overflow::Product));
+        assert_eq!(
+            part2(&[(u64::MAX, 0), (u64::MAX, 0)], &model),
+            Err(Overflow::KernedTime)
+        );
+    }
+
+    #[test]
+    fn test_kern() {
+        assert_eq!(kern(&[7, 15, 30]), Some(71530));
+        assert_eq!(kern(&[10, 0, 5]), Some(1005));
+        assert_eq!(
+            kern(&[18446744073709551615, 1]),
+            Some(184467440737095516151)
+        );
+        assert_eq!(kern(&[u64::MAX, u64::MAX]), None);
+    }
+}
+
+#[cfg(test)]
+mod fixtures {
+    use super::*;
+
+    fn solve(part: u8, input: &str) -> Answer {
+        solution::solve::<Day06>(part, input).unwrap()
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
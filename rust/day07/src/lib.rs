@@ -0,0 +1,594 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use aoc_utils::{solution, Answer, LineError, Solution};
+use serde_json::{json, Value};
+
+pub fn calculate(hands: &[Hand], ties: TiePolicy) -> Result<u64, DuplicateHands> {
+    Ok(hands
+        .iter()
+        .zip(rank(hands, ties)?)
+        .fold(0, |acc, (hand, rank)| acc + hand.bid * rank))
+}
+
+/// The hands read both without and with jokers, as parts 1 and 2 need.
+pub struct Hands {
+    pub without_jokers: Vec<Hand>,
+    pub with_jokers: Vec<Hand>,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Hands;
+
+    fn parse(input: &str) -> solution::Result<Self::Input<'_>> {
+        Ok(Hands {
+            without_jokers: parse_input(input, JokerKind::Ignore)?,
+            with_jokers: parse_input(input, JokerKind::Consider)?,
+        })
+    }
+
+    fn part1(hands: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(calculate(&hands.without_jokers, TiePolicy::InputOrder)?)
+    }
+
+    fn part2(hands: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(calculate(&hands.with_jokers, TiePolicy::InputOrder)?)
+    }
+}
+
+/// How identical hands, which only differ in their bids, are ranked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TiePolicy {
+    /// Identical hands share the lowest of their ranks, and the next hand
+    /// skips the ranks they would have taken.
+    SharedRank,
+    /// The hand listed first in the input ranks lower.
+    InputOrder,
+    /// Identical hands are rejected.
+    Error,
+}
+
+/// Groups of indices of hands with identical cards.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DuplicateHands(pub Vec<Vec<usize>>);
+
+impl fmt::Display for DuplicateHands {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups = self
+            .0
+            .iter()
+            .map(|group| {
+                let indices = group.iter().map(|idx| format!("#{}", idx + 1));
+                indices.collect::<Vec<_>>().join(", ")
+            })
+            .collect::<Vec<_>>();
+
+        write!(f, "duplicate hands: {}", groups.join("; "))
+    }
+}
+
+impl std::error::Error for DuplicateHands {}
+
+pub fn find_duplicates(hands: &[Hand]) -> Vec<Vec<usize>> {
+    let mut groups: HashMap<&[char; 5], Vec<usize>> = HashMap::new();
+
+    for (idx, hand) in hands.iter().enumerate() {
+        groups.entry(&hand.cards.cards).or_default().push(idx);
+    }
+
+    let mut duplicates = groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .collect::<Vec<_>>();
+    duplicates.sort();
+    duplicates
+}
+
+/// Returns the rank of each hand, in input order.
+pub fn rank(hands: &[Hand], ties: TiePolicy) -> Result<Vec<u64>, DuplicateHands> {
+    if ties == TiePolicy::Error {
+        let duplicates = find_duplicates(hands);
+
+        if !duplicates.is_empty() {
+            return Err(DuplicateHands(duplicates));
+        }
+    }
+
+    // `sort_by` is stable, so identical hands stay in input order.
+    let mut order = (0..hands.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| hands[*a].cmp(&hands[*b]));
+
+    let mut ranks = vec![0; hands.len()];
+
+    for (idx, &hand_idx) in order.iter().enumerate() {
+        ranks[hand_idx] = match idx.checked_sub(1).map(|prev| order[prev]) {
+            Some(prev) if ties == TiePolicy::SharedRank && hands[prev] == hands[hand_idx] => {
+                ranks[prev]
+            }
+            _ => (idx + 1) as u64,
+        };
+    }
+
+    Ok(ranks)
+}
+
+#[derive(Debug)]
+pub struct HandReport<'a> {
+    pub hand: &'a Hand,
+    pub joker_as: Option<char>,
+    pub rank: u64,
+}
+
+impl HandReport<'_> {
+    pub fn winnings(&self) -> u64 {
+        self.hand.bid * self.rank
+    }
+}
+
+pub fn report(hands: &[Hand], ties: TiePolicy) -> Result<Vec<HandReport<'_>>, DuplicateHands> {
+    Ok(hands
+        .iter()
+        .zip(rank(hands, ties)?)
+        .map(|(hand, rank)| HandReport {
+            hand,
+            joker_as: match hand.cards.joker_kind {
+                JokerKind::Consider => joker_substitute(&hand.cards.cards),
+                JokerKind::Ignore => None,
+            },
+            rank,
+        })
+        .collect())
+}
+
+pub fn histogram<'a>(reports: &[HandReport<'a>]) -> BTreeMap<&'a HandKind, usize> {
+    let mut histogram = BTreeMap::new();
+
+    for report in reports {
+        *histogram.entry(&report.hand.kind).or_insert(0) += 1;
+    }
+
+    histogram
+}
+
+pub fn report_table(reports: &[HandReport]) -> String {
+    let mut table = format!(
+        "{:<6} {:<13} {:<5} {:>5} {:>5} {:>8}\n",
+        "hand", "kind", "joker", "rank", "bid", "winnings"
+    );
+
+    for report in reports {
+        table += &format!(
+            "{:<6} {:<13} {:<5} {:>5} {:>5} {:>8}\n",
+            report.hand.cards.cards.iter().collect::<String>(),
+            format!("{:?}", report.hand.kind),
+            report.joker_as.unwrap_or('-'),
+            report.rank,
+            report.hand.bid,
+            report.winnings()
+        );
+    }
+
+    table += &format!(
+        "total: {}\n",
+        reports.iter().map(HandReport::winnings).sum::<u64>()
+    );
+
+    for (kind, count) in histogram(reports) {
+        table += &format!(
+            "{:<13} {:>5} {}\n",
+            format!("{:?}", kind),
+            count,
+            "#".repeat(count)
+        );
+    }
+
+    table
+}
+
+pub fn report_json(reports: &[HandReport]) -> Value {
+    let hands = reports
+        .iter()
+        .map(|report| {
+            json!({
+                "hand": report.hand.cards.cards.iter().collect::<String>(),
+                "kind": format!("{:?}", report.hand.kind),
+                "joker": report.joker_as.map(String::from),
+                "rank": report.rank,
+                "bid": report.hand.bid,
+                "winnings": report.winnings(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let histogram = histogram(reports)
+        .into_iter()
+        .map(|(kind, count)| (format!("{:?}", kind), json!(count)))
+        .collect::<serde_json::Map<_, _>>();
+
+    json!({
+        "hands": hands,
+        "histogram": histogram,
+        "total": reports.iter().map(HandReport::winnings).sum::<u64>(),
+    })
+}
+
+/// The card the jokers pretend to be when `JokerKind::Consider` applies: the
+/// most common other card, preferring the strongest one on ties.
+pub fn joker_substitute(cards: &[char; 5]) -> Option<char> {
+    if !cards.contains(&'J') {
+        return None;
+    }
+
+    CARD_STRENGTH
+        .iter()
+        .filter(|&&c| c != 'J')
+        .max_by_key(|&&c| cards.iter().filter(|&&card| card == c).count())
+        .copied()
+}
+
+pub const CARD_STRENGTH: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JokerKind {
+    Ignore,
+    Consider,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Cards {
+    pub cards: [char; 5],
+    pub joker_kind: JokerKind,
+}
+
+impl PartialOrd for Cards {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cards {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for (a, b) in self.cards.iter().zip(other.cards.iter()) {
+            if a != b {
+                if self.joker_kind == JokerKind::Consider {
+                    match (a, b) {
+                        ('J', _) => {
+                            return Ordering::Less;
+                        }
+                        (_, 'J') => {
+                            return Ordering::Greater;
+                        }
+                        _ => {}
+                    }
+                }
+
+                let x = CARD_STRENGTH.iter().position(|&c| c == *a).unwrap();
+                let y = CARD_STRENGTH.iter().position(|&c| c == *b).unwrap();
+
+                return x.cmp(&y);
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+#[derive(Debug, Eq)]
+pub struct Hand {
+    pub cards: Cards,
+    pub kind: HandKind,
+    pub bid: u64,
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cards == other.cards && self.kind == other.kind
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.kind == other.kind {
+            self.cards.cmp(&other.cards)
+        } else {
+            self.kind.cmp(&other.kind)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandKind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+pub fn parse_input(input: &str, joker_kind: JokerKind) -> Result<Vec<Hand>, LineError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| {
+            let invalid = |value: &str| LineError {
+                line: idx + 1,
+                value: value.to_string(),
+            };
+
+            let (hand, bid) = line.split_once(' ').ok_or_else(|| invalid(line))?;
+            let cards: [char; 5] = hand
+                .chars()
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| invalid(hand))?;
+
+            if !cards.iter().all(|card| CARD_STRENGTH.contains(card)) {
+                return Err(invalid(hand));
+            }
+
+            let bid = bid.trim().parse::<u64>().map_err(|_| invalid(bid))?;
+            let kind = parse_hand_kind(&cards, &joker_kind);
+
+            Ok(Hand {
+                cards: Cards { cards, joker_kind },
+                kind,
+                bid,
+            })
+        })
+        .collect()
+}
+
+pub fn parse_hand_kind(cards: &[char; 5], joker_kind: &JokerKind) -> HandKind {
+    let j_idx = CARD_STRENGTH.iter().position(|&c| c == 'J').unwrap();
+
+    let mut counts: [usize; 13] = [0; 13];
+
+    for card in cards {
+        let idx = CARD_STRENGTH.iter().position(|&c| c == *card).unwrap();
+        counts[idx] += 1;
+    }
+
+    // ignore zero index
+    let mut nums = [0; 6];
+
+    for (idx, count) in counts.iter().enumerate() {
+        if *joker_kind == JokerKind::Consider && idx == j_idx {
+            continue;
+        }
+
+        if *count != 0 {
+            nums[*count] += 1;
+        }
+    }
+
+    let j_count = counts[j_idx];
+
+    if *joker_kind == JokerKind::Consider && j_count > 0 {
+        match j_count {
+            4 | 5 => return HandKind::FiveOfAKind,
+            3 => match nums {
+                [_, 0, 1, 0, 0, 0] => return HandKind::FiveOfAKind,
+                [_, 2, 0, 0, 0, 0] => return HandKind::FourOfAKind,
+                _ => unreachable!("invalid joker count: {:?}", nums),
+            },
+            2 => match nums {
+                [_, 0, 0, 1, 0, 0] => return HandKind::FiveOfAKind,
+                [_, 1, 1, 0, 0, 0] => return HandKind::FourOfAKind,
+                [_, 3, 0, 0, 0, 0] => return HandKind::ThreeOfAKind,
+                _ => unreachable!("invalid joker count: {:?}", nums),
+            },
+            1 => match nums {
+                [_, 0, 0, 0, 1, 0] => return HandKind::FiveOfAKind,
+                [_, 1, 0, 1, 0, 0] => return HandKind::FourOfAKind,
+                [_, 0, 2, 0, 0, 0] => return HandKind::FullHouse,
+                [_, 2, 1, 0, 0, 0] => return HandKind::ThreeOfAKind,
+                [_, 4, 0, 0, 0, 0] => return HandKind::OnePair,
+                _ => unreachable!("invalid joker count: {:?}", nums),
+            },
+            _ => unreachable!("invalid joker count: {:?}", nums),
+        }
+    }
+
+    match nums {
+        [_, 0, 0, 0, 0, 1] => HandKind::FiveOfAKind,
+        [_, 1, 0, 0, 1, 0] => HandKind::FourOfAKind,
+        [_, 0, 1, 1, 0, 0] => HandKind::FullHouse,
+        [_, 2, 0, 1, 0, 0] => HandKind::ThreeOfAKind,
+        [_, _, 2, 0, 0, 0] => HandKind::TwoPair,
+        [_, _, 1, 0, 0, 0] => HandKind::OnePair,
+        _ => HandKind::HighCard,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../../tests/fixtures/day07/example1.txt");
+
+    #[test]
+    fn test_ties() {
+        const DUPLICATED_INPUT: &str = r"
+            32T3K 765
+            KK677 28
+            32T3K 10
+            T55J5 684
+            KK677 1
+            32T3K 3
+        ";
+
+        let hands = parse_input(DUPLICATED_INPUT, JokerKind::Ignore).unwrap();
+        assert_eq!(find_duplicates(&hands), vec![vec![0, 2, 5], vec![1, 4]]);
+
+        // 32T3K takes ranks 1-3, KK677 ranks 4-5 and T55J5 rank 6.
+        assert_eq!(
+            rank(&hands, TiePolicy::InputOrder),
+            Ok(vec![1, 4, 2, 6, 5, 3])
+        );
+        assert_eq!(
+            rank(&hands, TiePolicy::SharedRank),
+            Ok(vec![1, 4, 1, 6, 4, 1])
+        );
+        assert_eq!(
+            calculate(&hands, TiePolicy::SharedRank),
+            Ok(765 + 28 * 4 + 10 + 684 * 6 + 4 + 3)
+        );
+        assert_eq!(
+            calculate(&hands, TiePolicy::Error),
+            Err(DuplicateHands(vec![vec![0, 2, 5], vec![1, 4]]))
+        );
+
+        // Totals do not depend on where the other hands are listed.
+        let mut reordered = DUPLICATED_INPUT.trim().lines().collect::<Vec<_>>();
+        reordered.swap(1, 3);
+        let reordered = parse_input(&reordered.join("\n"), JokerKind::Ignore).unwrap();
+
+        for ties in [TiePolicy::InputOrder, TiePolicy::SharedRank] {
+            assert_eq!(calculate(&reordered, ties), calculate(&hands, ties));
+        }
+    }
+
+    #[test]
+    fn test_report() {
+        let hands = parse_input(EXAMPLE_INPUT, JokerKind::Consider).unwrap();
+        let reports = report(&hands, TiePolicy::Error).unwrap();
+
+        let summary = reports
+            .iter()
+            .map(|r| (r.joker_as, r.rank, r.winnings()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (None, 1, 765),
+                (Some('5'), 3, 2052),
+                (None, 2, 56),
+                (Some('T'), 5, 1100),
+                (Some('Q'), 4, 1932),
+            ]
+        );
+
+        let json = report_json(&reports);
+        assert_eq!(json["total"], 5905);
+        assert_eq!(json["histogram"]["FourOfAKind"], 3);
+        assert_eq!(json["hands"][3]["kind"], "FourOfAKind");
+    }
+
+    /// Every hand made of the 13 cards, in no particular order.
+    fn all_hands() -> impl Iterator<Item = [char; 5]> {
+        (0..13_usize.pow(5)).map(|mut n| {
+            let mut cards = ['2'; 5];
+
+            for card in cards.iter_mut() {
+                *card = CARD_STRENGTH[n % 13];
+                n /= 13;
+            }
+
+            cards
+        })
+    }
+
+    /// Every multiset of `count` cards, as the order of the substitutes does
+    /// not change the kind of a hand.
+    fn substitutes(count: usize, from: usize) -> Vec<Vec<char>> {
+        if count == 0 {
+            return vec![vec![]];
+        }
+
+        (from..13)
+            .flat_map(|idx| {
+                substitutes(count - 1, idx)
+                    .into_iter()
+                    .map(move |mut rest| {
+                        rest.push(CARD_STRENGTH[idx]);
+                        rest
+                    })
+            })
+            .collect()
+    }
+
+    /// Tries every card in place of every joker and keeps the best kind.
+    fn brute_force_kind(cards: &[char; 5], substitutes: &[Vec<Vec<char>>]) -> HandKind {
+        let jokers = cards.iter().filter(|&&c| c == 'J').count();
+
+        substitutes[jokers]
+            .iter()
+            .map(|substitute| {
+                let mut substitute = substitute.iter().copied();
+                let replaced = cards.map(|c| {
+                    if c == 'J' {
+                        substitute.next().unwrap()
+                    } else {
+                        c
+                    }
+                });
+
+                parse_hand_kind(&replaced, &JokerKind::Ignore)
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_joker_classification_exhaustive() {
+        let substitutes = (0..=5).map(|n| substitutes(n, 0)).collect::<Vec<_>>();
+
+        for cards in all_hands() {
+            let kind = parse_hand_kind(&cards, &JokerKind::Consider);
+            assert_eq!(kind, brute_force_kind(&cards, &substitutes), "{:?}", cards);
+
+            if let Some(joker_as) = joker_substitute(&cards) {
+                let replaced = cards.map(|c| if c == 'J' { joker_as } else { c });
+                assert_eq!(
+                    parse_hand_kind(&replaced, &JokerKind::Ignore),
+                    kind,
+                    "{:?} with jokers as {}",
+                    cards,
+                    joker_as
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |input: &str| parse_input(input, JokerKind::Ignore).unwrap_err();
+        let error = |line: usize, value: &str| LineError {
+            line,
+            value: value.to_string(),
+        };
+
+        assert_eq!(parse("32T3K 765\nT55J5"), error(2, "T55J5"));
+        assert_eq!(parse("32T3 765"), error(1, "32T3"));
+        assert_eq!(parse("32T3X 765"), error(1, "32T3X"));
+        assert_eq!(parse("\n32T3K -1"), error(2, "-1"));
+    }
+}
+
+#[cfg(test)]
+mod fixtures {
+    use super::*;
+
+    fn solve(part: u8, input: &str) -> Answer {
+        solution::solve::<Day07>(part, input).unwrap()
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
@@ -0,0 +1,865 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
+
+use aoc_utils::{math::checked_lcm, solution, Answer, Solution};
+use regex::Regex;
+
+pub fn find<F>(start: &str, has_finished: F, nav: &Navigation<'_>) -> Result<u64, NavError>
+where
+    F: Fn(&str) -> bool,
+{
+    walk_until(start, has_finished, nav, |_| {})
+}
+
+/// Returns each step taken from `start` as the node left and the direction
+/// followed.
+pub fn path<'a, F>(
+    start: &'a str,
+    has_finished: F,
+    nav: &Navigation<'a>,
+) -> Result<Vec<(&'a str, Direction)>, NavError>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut steps = Vec::new();
+    walk_until(start, has_finished, nav, |step| {
+        steps.push((step.from, step.direction))
+    })?;
+
+    Ok(steps)
+}
+
+/// Walks from `start` until `has_finished` holds, handing every step to
+/// `visit`. Gives up once a (node, instruction offset) state repeats, as
+/// the walk would then loop forever.
+pub fn walk_until<'a, F, V>(
+    start: &'a str,
+    has_finished: F,
+    nav: &Navigation<'a>,
+    mut visit: V,
+) -> Result<u64, NavError>
+where
+    F: Fn(&str) -> bool,
+    V: FnMut(&Step<'a>),
+{
+    let (start, _) = nav
+        .nodes
+        .get_key_value(start)
+        .ok_or_else(|| NavError::UnknownNode {
+            node: start.to_string(),
+            referenced_by: None,
+        })?;
+
+    if has_finished(start) {
+        return Ok(0);
+    }
+
+    let len = nav.instructions.len() as u64;
+    let mut seen = HashSet::from([(*start, 0)]);
+
+    for step in Walker::new(start, nav) {
+        visit(&step);
+
+        if has_finished(step.node) {
+            return Ok(step.count);
+        }
+
+        if !seen.insert((step.node, step.count % len)) {
+            return Err(NavError::Unreachable {
+                start: start.to_string(),
+                steps: step.count,
+            });
+        }
+    }
+
+    unreachable!("the walker never stops")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NavError {
+    NoInstructions,
+    UnknownNode {
+        node: String,
+        referenced_by: Option<String>,
+    },
+    Unreachable {
+        start: String,
+        steps: u64,
+    },
+    Overflow,
+    InvalidQuery(String),
+    NoMatchingStart(String),
+    /// A node line which is not `NAME = (LEFT, RIGHT)`.
+    InvalidNode(String),
+    InvalidDirection(char),
+}
+
+impl fmt::Display for NavError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NavError::NoInstructions => write!(f, "no instructions to follow"),
+            NavError::UnknownNode {
+                node,
+                referenced_by: Some(by),
+            } => write!(f, "node `{}` referenced by `{}` is not defined", node, by),
+            NavError::UnknownNode {
+                node,
+                referenced_by: None,
+            } => write!(f, "node `{}` is not defined", node),
+            NavError::Unreachable { start, steps } => write!(
+                f,
+                "no target is reachable from `{}`: the walk loops after {} steps",
+                start, steps
+            ),
+            NavError::Overflow => write!(f, "the number of steps overflows u64"),
+            NavError::InvalidQuery(reason) => write!(f, "invalid query: {}", reason),
+            NavError::NoMatchingStart(pattern) => {
+                write!(f, "no node matches the start pattern `{}`", pattern)
+            }
+            NavError::InvalidNode(line) => write!(f, "cannot parse node `{}`", line),
+            NavError::InvalidDirection(c) => write!(f, "invalid direction `{}`", c),
+        }
+    }
+}
+
+impl std::error::Error for NavError {}
+
+/// A single move through the network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step<'a> {
+    /// Number of steps taken so far, including this one.
+    pub count: u64,
+    pub from: &'a str,
+    pub direction: Direction,
+    pub node: &'a str,
+    /// Index of the instruction which was followed.
+    pub offset: usize,
+}
+
+/// Follows the instructions from a node forever, yielding every step.
+pub struct Walker<'n, 'a> {
+    nav: &'n Navigation<'a>,
+    current: &'a str,
+    count: u64,
+}
+
+impl<'n, 'a> Walker<'n, 'a> {
+    pub fn new(start: &'a str, nav: &'n Navigation<'a>) -> Self {
+        Self {
+            nav,
+            current: start,
+            count: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Walker<'_, 'a> {
+    type Item = Step<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = (self.count % self.nav.instructions.len() as u64) as usize;
+        let direction = self.nav.instructions[offset];
+        let node = self.nav.nodes.get(self.current).unwrap();
+        let from = self.current;
+
+        self.current = match direction {
+            Direction::Left => node.0,
+            Direction::Right => node.1,
+        };
+        self.count += 1;
+
+        Some(Step {
+            count: self.count,
+            from,
+            direction,
+            node: self.current,
+            offset,
+        })
+    }
+}
+
+/// Where a walk reached an end node, and the index of the instruction that
+/// would be followed next.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EndHit<'a> {
+    pub count: u64,
+    pub node: &'a str,
+    pub offset: usize,
+}
+
+/// The steps after which a walk repeats itself forever.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of steps before the walk enters its loop.
+    pub start: u64,
+    pub length: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Trace<'a> {
+    /// Every node visited, starting with the start node.
+    pub nodes: Vec<&'a str>,
+    pub end_hits: Vec<EndHit<'a>>,
+    pub cycle: Cycle,
+}
+
+/// Walks from `start` until a (node, instruction offset) state repeats,
+/// which is when the walk has entered its loop.
+pub fn trace<'a, F>(start: &'a str, is_end: F, nav: &Navigation<'a>) -> Trace<'a>
+where
+    F: Fn(&str) -> bool,
+{
+    let len = nav.instructions.len() as u64;
+    let mut seen = HashMap::from([((start, 0), 0)]);
+    let mut nodes = vec![start];
+    let mut end_hits = Vec::new();
+
+    if is_end(start) {
+        end_hits.push(EndHit {
+            count: 0,
+            node: start,
+            offset: 0,
+        });
+    }
+
+    for step in Walker::new(start, nav) {
+        let offset = (step.count % len) as usize;
+
+        if let Some(first) = seen.insert((step.node, offset), step.count) {
+            return Trace {
+                nodes,
+                end_hits,
+                cycle: Cycle {
+                    start: first,
+                    length: step.count - first,
+                },
+            };
+        }
+
+        nodes.push(step.node);
+
+        if is_end(step.node) {
+            end_hits.push(EndHit {
+                count: step.count,
+                node: step.node,
+                offset,
+            });
+        }
+    }
+
+    unreachable!("the walker never stops")
+}
+
+/// Collapses consecutive visits of the same node into `(node, times)`.
+pub fn run_length<'a>(nodes: &[&'a str]) -> Vec<(&'a str, usize)> {
+    let mut runs: Vec<(&str, usize)> = Vec::new();
+
+    for node in nodes {
+        match runs.last_mut() {
+            Some((last, times)) if last == node => *times += 1,
+            _ => runs.push((node, 1)),
+        }
+    }
+
+    runs
+}
+
+pub fn is_start(node: &str) -> bool {
+    node.ends_with('A')
+}
+
+pub fn is_end(node: &str) -> bool {
+    node.ends_with('Z')
+}
+
+/// Nodes and edges to emphasise when exporting the network.
+#[derive(Debug, Default)]
+pub struct Highlight<'a> {
+    pub endpoints: bool,
+    pub path: HashSet<(&'a str, Direction)>,
+}
+
+/// The edges walked by part 1, or by every ghost of part 2 if there is no
+/// `AAA` node. Fails if any of those walks does.
+pub fn walked_edges<'a>(nav: &Navigation<'a>) -> Result<HashSet<(&'a str, Direction)>, NavError> {
+    match nav.nodes.get_key_value("AAA") {
+        Some((start, _)) => Ok(path(start, |node| node == "ZZZ", nav)?
+            .into_iter()
+            .collect()),
+        None => {
+            let mut edges = HashSet::new();
+
+            for start in nav.nodes.keys().filter(|k| is_start(k)) {
+                edges.extend(path(start, is_end, nav)?);
+            }
+
+            Ok(edges)
+        }
+    }
+}
+
+/// The edges of the network sorted by node, with both directions merged
+/// when they lead to the same node.
+pub fn edges<'a>(nav: &Navigation<'a>) -> Vec<(&'a str, &'a str, Vec<Direction>)> {
+    let mut names = nav.nodes.keys().copied().collect::<Vec<_>>();
+    names.sort();
+
+    names
+        .into_iter()
+        .flat_map(|name| {
+            let (left, right) = nav.nodes[name];
+
+            if left == right {
+                vec![(name, left, vec![Direction::Left, Direction::Right])]
+            } else {
+                vec![
+                    (name, left, vec![Direction::Left]),
+                    (name, right, vec![Direction::Right]),
+                ]
+            }
+        })
+        .collect()
+}
+
+pub fn label(directions: &[Direction]) -> String {
+    directions
+        .iter()
+        .map(|d| match d {
+            Direction::Left => "L",
+            Direction::Right => "R",
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+pub fn to_dot(nav: &Navigation, highlight: &Highlight) -> String {
+    let mut dot = String::from("digraph network {\n");
+
+    if highlight.endpoints {
+        let mut names = nav.nodes.keys().copied().collect::<Vec<_>>();
+        names.sort();
+
+        for name in names {
+            if is_start(name) {
+                dot += &format!("    \"{}\" [style=filled, fillcolor=palegreen];\n", name);
+            } else if is_end(name) {
+                dot += &format!("    \"{}\" [style=filled, fillcolor=salmon];\n", name);
+            }
+        }
+    }
+
+    for (from, to, directions) in edges(nav) {
+        let walked = directions
+            .iter()
+            .any(|d| highlight.path.contains(&(from, *d)));
+
+        dot += &format!(
+            "    \"{}\" -> \"{}\" [label=\"{}\"",
+            from,
+            to,
+            label(&directions)
+        );
+
+        if walked {
+            dot += ", color=red, penwidth=2";
+        }
+
+        dot += "];\n";
+    }
+
+    dot += "}\n";
+    dot
+}
+
+pub fn to_mermaid(nav: &Navigation, highlight: &Highlight) -> String {
+    let mut mermaid = String::from("flowchart LR\n");
+
+    for (from, to, directions) in edges(nav) {
+        let walked = directions
+            .iter()
+            .any(|d| highlight.path.contains(&(from, *d)));
+        let arrow = if walked { "==>" } else { "-->" };
+
+        mermaid += &format!("    {} {}|{}| {}\n", from, arrow, label(&directions), to);
+    }
+
+    if highlight.endpoints {
+        mermaid += "    classDef startNode fill:#9f9\n";
+        mermaid += "    classDef endNode fill:#f99\n";
+
+        let mut names = nav.nodes.keys().copied().collect::<Vec<_>>();
+        names.sort();
+
+        for name in names {
+            if is_start(name) {
+                mermaid += &format!("    class {} startNode\n", name);
+            } else if is_end(name) {
+                mermaid += &format!("    class {} endNode\n", name);
+            }
+        }
+    }
+
+    mermaid
+}
+
+pub fn part1(nav: &Navigation) -> Result<u64, NavError> {
+    const QUERY: &str = "AAA -> ZZZ";
+    run_query(&QUERY.parse()?, nav).map(|result| result.steps)
+}
+
+pub fn part2(nav: &Navigation) -> Result<u64, NavError> {
+    const QUERY: &str = "*A -> *Z";
+    run_query(&QUERY.parse()?, nav).map(|result| result.steps)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Navigation<'a>;
+
+    fn parse(input: &str) -> solution::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(nav: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part1(nav)?)
+    }
+
+    fn part2(nav: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part2(nav)?)
+    }
+}
+
+/// A set of node names: `re:` introduces a regex, a pattern containing `*`
+/// or `?` is a glob, and anything else is an exact name.
+#[derive(Debug)]
+pub enum Pattern {
+    Exact(String),
+    Glob(String),
+    Regex(Regex),
+}
+
+impl Pattern {
+    pub fn matches(&self, node: &str) -> bool {
+        match self {
+            Pattern::Exact(name) => node == name,
+            Pattern::Glob(glob) => glob_matches(glob.as_bytes(), node.as_bytes()),
+            Pattern::Regex(regex) => regex.is_match(node),
+        }
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = NavError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            Err(NavError::InvalidQuery("empty pattern".to_string()))
+        } else if let Some(regex) = s.strip_prefix("re:") {
+            Regex::new(regex)
+                .map(Pattern::Regex)
+                .map_err(|e| NavError::InvalidQuery(e.to_string()))
+        } else if s.contains(['*', '?']) {
+            Ok(Pattern::Glob(s.to_string()))
+        } else {
+            Ok(Pattern::Exact(s.to_string()))
+        }
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Pattern::Exact(name) => write!(f, "{}", name),
+            Pattern::Glob(glob) => write!(f, "{}", glob),
+            Pattern::Regex(regex) => write!(f, "re:{}", regex),
+        }
+    }
+}
+
+pub fn glob_matches(glob: &[u8], name: &[u8]) -> bool {
+    match (glob.first(), name.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => {
+            glob_matches(&glob[1..], name) || (!name.is_empty() && glob_matches(glob, &name[1..]))
+        }
+        (Some(b'?'), Some(_)) => glob_matches(&glob[1..], &name[1..]),
+        (Some(g), Some(n)) if g == n => glob_matches(&glob[1..], &name[1..]),
+        _ => false,
+    }
+}
+
+/// Walks every node matching `start` until it reaches a node matching `end`,
+/// written as `<start> -> <end>`. The last `->` separates the two, so a `re:`
+/// start pattern may contain one but the end pattern may not.
+#[derive(Debug)]
+pub struct Query {
+    pub start: Pattern,
+    pub end: Pattern,
+}
+
+impl FromStr for Query {
+    type Err = NavError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .rsplit_once("->")
+            .ok_or_else(|| NavError::InvalidQuery(format!("missing `->` in `{}`", s)))?;
+
+        Ok(Query {
+            start: start.parse()?,
+            end: end.parse()?,
+        })
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> {}", self.start, self.end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct QueryResult<'a> {
+    /// Steps needed from each matching start node, sorted by name.
+    pub starts: Vec<(&'a str, u64)>,
+    /// Steps until every walk is on an end node at the same time.
+    pub steps: u64,
+}
+
+pub fn run_query<'a>(query: &Query, nav: &Navigation<'a>) -> Result<QueryResult<'a>, NavError> {
+    let mut names = match &query.start {
+        // Report an unknown exact start rather than an empty match.
+        Pattern::Exact(name) => vec![nav
+            .nodes
+            .get_key_value(name.as_str())
+            .map(|(k, _)| *k)
+            .ok_or_else(|| NavError::UnknownNode {
+                node: name.clone(),
+                referenced_by: None,
+            })?],
+        pattern => nav
+            .nodes
+            .keys()
+            .copied()
+            .filter(|k| pattern.matches(k))
+            .collect(),
+    };
+    names.sort();
+
+    if names.is_empty() {
+        return Err(NavError::NoMatchingStart(query.start.to_string()));
+    }
+
+    let starts = names
+        .into_iter()
+        .map(|name| Ok((name, find(name, |node| query.end.matches(node), nav)?)))
+        .collect::<Result<Vec<_>, NavError>>()?;
+
+    let steps = starts
+        .iter()
+        .try_fold(1, |acc, (_, steps)| checked_lcm(acc, *steps))
+        .ok_or(NavError::Overflow)?;
+
+    Ok(QueryResult { starts, steps })
+}
+
+pub fn parse(input: &str) -> Result<Navigation<'_>, NavError> {
+    let mut lines = input.lines().skip_while(|l| l.trim().is_empty());
+
+    let instructions = lines
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .chars()
+        .map(Direction::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let nodes = lines
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (name, next) = line
+                .split_once(" = ")
+                .ok_or_else(|| NavError::InvalidNode(line.to_string()))?;
+            let value = next
+                .trim_start_matches('(')
+                .trim_end_matches(')')
+                .split_once(", ")
+                .ok_or_else(|| NavError::InvalidNode(line.to_string()))?;
+
+            Ok((name, value))
+        })
+        .collect::<Result<HashMap<_, _>, NavError>>()?;
+
+    if instructions.is_empty() {
+        return Err(NavError::NoInstructions);
+    }
+
+    let mut names = nodes.keys().collect::<Vec<_>>();
+    names.sort();
+
+    for name in names {
+        let (left, right) = nodes[name];
+
+        for next in [left, right] {
+            if !nodes.contains_key(next) {
+                return Err(NavError::UnknownNode {
+                    node: next.to_string(),
+                    referenced_by: Some(name.to_string()),
+                });
+            }
+        }
+    }
+
+    Ok(Navigation {
+        instructions,
+        nodes,
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = NavError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(NavError::InvalidDirection(c)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Navigation<'a> {
+    pub instructions: Vec<Direction>,
+    pub nodes: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queries() {
+        const EXAMPLE_INPUT_2: &str = include_str!("../../tests/fixtures/day08/example3.txt");
+        let nav = parse(EXAMPLE_INPUT_2).unwrap();
+        let run = |query: &str| run_query(&query.parse().unwrap(), &nav);
+
+        assert_eq!(
+            run("??A -> *Z"),
+            Ok(QueryResult {
+                starts: vec![("11A", 2), ("22A", 3)],
+                steps: 6
+            })
+        );
+        assert_eq!(run("re:^2.A$ -> 22Z").map(|r| r.steps), Ok(3));
+        assert_eq!(run("re:^(2.A|A->B)$ -> 22Z").map(|r| r.steps), Ok(3));
+        assert_eq!(run("22A -> re:C$").map(|r| r.steps), Ok(2));
+        assert_eq!(run("11B -> 11*").map(|r| r.steps), Ok(0));
+        assert_eq!(
+            run("3*A -> *Z"),
+            Err(NavError::NoMatchingStart("3*A".to_string()))
+        );
+        assert!(matches!(
+            "re:( -> ZZZ".parse::<Query>(),
+            Err(NavError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            "AAA ZZZ".parse::<Query>(),
+            Err(NavError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        const LOOPING_INPUT: &str = "
+            LR
+
+            AAA = (BBB, CCC)
+            BBB = (AAA, AAA)
+            CCC = (ZZZ, ZZZ)
+            ZZZ = (ZZZ, ZZZ)
+        ";
+        let nav = parse(LOOPING_INPUT).unwrap();
+
+        assert_eq!(
+            part1(&nav),
+            Err(NavError::Unreachable {
+                start: "AAA".to_string(),
+                steps: 2
+            })
+        );
+        assert_eq!(
+            walked_edges(&nav),
+            Err(NavError::Unreachable {
+                start: "AAA".to_string(),
+                steps: 2
+            })
+        );
+        assert_eq!(find("CCC", |node| node == "ZZZ", &nav), Ok(1));
+        assert_eq!(
+            find("YYY", |node| node == "ZZZ", &nav),
+            Err(NavError::UnknownNode {
+                node: "YYY".to_string(),
+                referenced_by: None
+            })
+        );
+
+        assert_eq!(
+            parse("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)").map(|_| ()),
+            Err(NavError::UnknownNode {
+                node: "BBB".to_string(),
+                referenced_by: Some("AAA".to_string())
+            })
+        );
+        assert_eq!(
+            parse("L\n\nAAA (BBB, CCC)").map(|_| ()),
+            Err(NavError::InvalidNode("AAA (BBB, CCC)".to_string()))
+        );
+        assert_eq!(
+            parse("LR\n\nAAA = (AAA)").map(|_| ()),
+            Err(NavError::InvalidNode("AAA = (AAA)".to_string()))
+        );
+        assert_eq!(
+            parse("LXR\n\nAAA = (AAA, AAA)").map(|_| ()),
+            Err(NavError::InvalidDirection('X'))
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        const EXAMPLE_INPUT_2: &str = include_str!("../../tests/fixtures/day08/example3.txt");
+        let nav = parse(EXAMPLE_INPUT_2).unwrap();
+
+        let trace_11 = trace("11A", is_end, &nav);
+        assert_eq!(trace_11.nodes, vec!["11A", "11B", "11Z"]);
+        assert_eq!(
+            trace_11.cycle,
+            Cycle {
+                start: 1,
+                length: 2
+            }
+        );
+        assert_eq!(
+            trace_11.end_hits,
+            vec![EndHit {
+                count: 2,
+                node: "11Z",
+                offset: 0
+            }]
+        );
+
+        let trace_22 = trace("22A", is_end, &nav);
+        assert_eq!(
+            trace_22.cycle,
+            Cycle {
+                start: 1,
+                length: 6
+            }
+        );
+        assert_eq!(
+            trace_22
+                .end_hits
+                .iter()
+                .map(|hit| (hit.count, hit.offset))
+                .collect::<Vec<_>>(),
+            vec![(3, 1), (6, 0)]
+        );
+
+        let steps = Walker::new("XXX", &nav).take(3).collect::<Vec<_>>();
+        assert!(steps.iter().all(|step| step.node == "XXX"));
+        assert_eq!(
+            run_length(&["11A", "XXX", "XXX", "XXX", "11A"]),
+            vec![("11A", 1), ("XXX", 3), ("11A", 1)]
+        );
+    }
+
+    #[test]
+    fn test_export() {
+        const EXAMPLE_INPUT: &str = include_str!("../../tests/fixtures/day08/example2.txt");
+        let nav = parse(EXAMPLE_INPUT).unwrap();
+        let highlight = Highlight {
+            endpoints: true,
+            path: walked_edges(&nav).unwrap(),
+        };
+
+        assert_eq!(
+            to_dot(&nav, &highlight),
+            r#"digraph network {
+    "AAA" [style=filled, fillcolor=palegreen];
+    "ZZZ" [style=filled, fillcolor=salmon];
+    "AAA" -> "BBB" [label="L/R", color=red, penwidth=2];
+    "BBB" -> "AAA" [label="L", color=red, penwidth=2];
+    "BBB" -> "ZZZ" [label="R", color=red, penwidth=2];
+    "ZZZ" -> "ZZZ" [label="L/R"];
+}
+"#
+        );
+
+        assert_eq!(
+            to_mermaid(&nav, &Highlight::default()),
+            "flowchart LR
+    AAA -->|L/R| BBB
+    BBB -->|L| AAA
+    BBB -->|R| ZZZ
+    ZZZ -->|L/R| ZZZ
+"
+        );
+    }
+
+    #[test]
+    fn test_export_ghosts() {
+        const EXAMPLE_INPUT: &str = include_str!("../../tests/fixtures/day08/example3.txt");
+        let nav = parse(EXAMPLE_INPUT).unwrap();
+        let highlight = Highlight {
+            endpoints: true,
+            path: walked_edges(&nav).unwrap(),
+        };
+
+        assert_eq!(
+            to_dot(&nav, &highlight),
+            r#"digraph network {
+    "11A" [style=filled, fillcolor=palegreen];
+    "11Z" [style=filled, fillcolor=salmon];
+    "22A" [style=filled, fillcolor=palegreen];
+    "22Z" [style=filled, fillcolor=salmon];
+    "11A" -> "11B" [label="L", color=red, penwidth=2];
+    "11A" -> "XXX" [label="R"];
+    "11B" -> "XXX" [label="L"];
+    "11B" -> "11Z" [label="R", color=red, penwidth=2];
+    "11Z" -> "11B" [label="L"];
+    "11Z" -> "XXX" [label="R"];
+    "22A" -> "22B" [label="L", color=red, penwidth=2];
+    "22A" -> "XXX" [label="R"];
+    "22B" -> "22C" [label="L/R", color=red, penwidth=2];
+    "22C" -> "22Z" [label="L/R", color=red, penwidth=2];
+    "22Z" -> "22B" [label="L/R"];
+    "XXX" -> "XXX" [label="L/R"];
+}
+"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod fixtures {
+    use super::*;
+
+    fn solve(part: u8, input: &str) -> Answer {
+        solution::solve::<Day08>(part, input).unwrap()
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
@@ -1,168 +1,136 @@
-use std::collections::HashMap;
+use std::collections::HashSet;
 
-fn main() {
-    let input = include_str!("./input");
-    let nav = parse(input);
-
-    println!("Part 1: {}", part1(&nav));
-    println!("Part 2: {}", part2(&nav));
-}
-
-fn find<F>(start: &str, has_finished: F, nav: &Navigation<'_>) -> u64
-where
-    F: Fn(&str) -> bool,
-{
-    let mut count = 0;
-    let mut current = start;
-
-    let mut instructions = nav.instructions.iter().cycle();
+use aoc_utils::runner::{Args, CountingAlloc, Runner};
+use day08::{
+    is_end, parse, part1, part2, run_length, run_query, to_dot, to_mermaid, trace, walked_edges,
+    Highlight, Navigation, Query,
+};
 
-    while !has_finished(current) {
-        let node = nav.nodes.get(current).unwrap();
-        let direction = instructions.next().unwrap();
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
 
-        match direction {
-            Direction::Left => current = node.0,
-            Direction::Right => current = node.1,
+fn main() {
+    let input = include_str!("./input");
+    let mut runner = Runner::from_args();
+    let nav = match runner.parse(|| parse(input)) {
+        Ok(nav) => nav,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
         }
-
-        count += 1;
-    }
-
-    count
-}
-
-fn part1(nav: &Navigation) -> u64 {
-    const START: &str = "AAA";
-    const END: &str = "ZZZ";
-    find(START, |node| node == END, nav)
-}
-
-fn part2(nav: &Navigation) -> u64 {
-    nav.nodes
-        .keys()
-        .filter_map(|k| {
-            if k.ends_with('A') {
-                Some(find(k, |node| node.ends_with('Z'), nav))
-            } else {
-                None
+    };
+
+    let mut args = Args::from_env();
+    let plain = args.flag("--plain");
+    let path = args.flag("--path");
+    let rle = args.flag("--rle");
+    let nodes = args.flag("--nodes");
+    let file = args.value("--file");
+
+    match args.rest().as_slice() {
+        ["export", format] => {
+            let highlight = Highlight {
+                endpoints: !plain,
+                path: if path {
+                    match walked_edges(&nav) {
+                        Ok(edges) => edges,
+                        Err(e) => {
+                            eprintln!("error: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                } else {
+                    HashSet::new()
+                },
+            };
+
+            match *format {
+                "dot" => print!("{}", to_dot(&nav, &highlight)),
+                "mermaid" => print!("{}", to_mermaid(&nav, &highlight)),
+                _ => {
+                    eprintln!("error: unknown export format: {}", format);
+                    std::process::exit(1);
+                }
             }
-        })
-        .fold(1, lcm)
-}
-
-fn lcm(a: u64, b: u64) -> u64 {
-    a * b / gcd(a, b)
-}
-
-fn gcd(a: u64, b: u64) -> u64 {
-    let mut a = a;
-    let mut b = b;
-
-    while b != 0 {
-        let t = b;
-        b = a % b;
-        a = t;
-    }
-
-    a
-}
-
-fn parse(input: &str) -> Navigation<'_> {
-    let mut lines = input.lines().skip_while(|l| l.trim().is_empty());
-
-    let instructions = lines
-        .next()
-        .unwrap()
-        .trim()
-        .chars()
-        .map(|c| c.into())
-        .collect::<Vec<_>>();
-
-    let nodes = lines
-        .filter_map(|line| {
-            let line = line.trim();
-
-            if line.is_empty() {
-                return None;
+        }
+        ["trace", start] => {
+            let (start, _) = match nav.nodes.get_key_value(start) {
+                Some(node) => node,
+                None => {
+                    eprintln!("error: unknown node: {}", start);
+                    std::process::exit(1);
+                }
+            };
+            let trace = trace(start, is_end, &nav);
+
+            println!(
+                "{}: enters its loop after {} steps, loop length {}",
+                start, trace.cycle.start, trace.cycle.length
+            );
+
+            for hit in &trace.end_hits {
+                println!(
+                    "  reaches {} after {} steps at instruction {}",
+                    hit.node, hit.count, hit.offset
+                );
             }
 
-            let mut parts = line.splitn(2, " = ");
-            let name = parts.next().unwrap();
-            let next = parts
-                .next()
-                .unwrap()
-                .trim_start_matches('(')
-                .trim_end_matches(')')
-                .split(", ")
-                .collect::<Vec<_>>();
-
-            let value = (next[0], next[1]);
-            Some((name, value))
-        })
-        .collect::<HashMap<_, _>>();
-
-    Navigation {
-        instructions,
-        nodes,
+            if rle {
+                for (node, times) in run_length(&trace.nodes) {
+                    println!("  {} x{}", node, times);
+                }
+            } else if nodes {
+                println!("  {}", trace.nodes.join(" -> "));
+            }
+        }
+        ["query", queries @ ..] => match &file {
+            Some(path) => run_file(path, &nav),
+            None => run_batch(queries, &nav),
+        },
+        _ => {
+            runner.try_part(1, || part1(&nav));
+            runner.try_part(2, || part2(&nav));
+            runner.finish();
+        }
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-enum Direction {
-    Left,
-    Right,
-}
-
-impl From<char> for Direction {
-    fn from(c: char) -> Self {
-        match c {
-            'L' => Self::Left,
-            'R' => Self::Right,
-            _ => panic!("Invalid direction: {}", c),
+/// Runs the queries in the file at `path`, one per line, skipping blank
+/// lines and `#` comments.
+fn run_file(path: &str, nav: &Navigation) {
+    let queries = match aoc_utils::read_file(path) {
+        Ok(queries) => queries,
+        Err(e) => {
+            eprintln!("error: cannot read `{}`: {}", path, e);
+            std::process::exit(1);
         }
-    }
-}
+    };
+    let queries = queries
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect::<Vec<_>>();
 
-#[derive(Debug)]
-struct Navigation<'a> {
-    instructions: Vec<Direction>,
-    nodes: HashMap<&'a str, (&'a str, &'a str)>,
+    run_batch(&queries, nav);
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        const EXAMPLE_INPUT: &str = "
-            LLR
-
-            AAA = (BBB, BBB)
-            BBB = (AAA, ZZZ)
-            ZZZ = (ZZZ, ZZZ)
-        ";
-        let nav = parse(EXAMPLE_INPUT);
-        assert_eq!(part1(&nav), 6);
-    }
-
-    #[test]
-    fn test_part2() {
-        const EXAMPLE_INPUT_2: &str = "
-            LR
-
-            11A = (11B, XXX)
-            11B = (XXX, 11Z)
-            11Z = (11B, XXX)
-            22A = (22B, XXX)
-            22B = (22C, 22C)
-            22C = (22Z, 22Z)
-            22Z = (22B, 22B)
-            XXX = (XXX, XXX)
-        ";
-
-        let nav = parse(EXAMPLE_INPUT_2);
-        assert_eq!(part2(&nav), 6);
+fn run_batch(queries: &[&str], nav: &Navigation) {
+    for query in queries {
+        match query
+            .parse::<Query>()
+            .and_then(|query| run_query(&query, nav))
+        {
+            Ok(result) if result.starts.len() == 1 => println!("{}: {}", query, result.steps),
+            Ok(result) => {
+                let starts = result
+                    .starts
+                    .iter()
+                    .map(|(name, steps)| format!("{}: {}", name, steps))
+                    .collect::<Vec<_>>();
+
+                println!("{}: {} ({})", query, result.steps, starts.join(", "));
+            }
+            Err(e) => println!("{}: error: {}", query, e),
+        }
     }
 }
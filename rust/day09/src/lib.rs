@@ -0,0 +1,442 @@
+use std::{
+    fmt,
+    ops::{Add, Sub},
+};
+
+use aoc_utils::{solution, Answer, LineError, Solution};
+use num_rational::Ratio;
+use num_traits::Zero;
+
+/// The values a history can be made of.
+pub trait Number:
+    Clone + PartialEq + Zero + Add<Output = Self> + Sub<Output = Self> + fmt::Display
+{
+    fn parse(s: &str) -> Option<Self>;
+}
+
+impl Number for i64 {
+    fn parse(s: &str) -> Option<Self> {
+        s.parse().ok()
+    }
+}
+
+/// Accepts integers, fractions such as `-3/4` and decimals such as `1.25`,
+/// all of which are kept exact.
+impl Number for Ratio<i128> {
+    fn parse(s: &str) -> Option<Self> {
+        if let Some((numer, denom)) = s.split_once('/') {
+            let numer = numer.parse::<i128>().ok()?;
+            let denom = denom.parse::<i128>().ok()?;
+
+            return (denom != 0).then(|| Ratio::new(numer, denom));
+        }
+
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+
+        if !frac.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let denom = 10_i128.checked_pow(frac.len() as u32)?;
+        let frac = if frac.is_empty() {
+            0
+        } else {
+            frac.parse::<i128>().ok()?
+        };
+        let int_part = int.parse::<i128>().ok()?.checked_mul(denom)?;
+        let numer = if int.starts_with('-') {
+            int_part.checked_sub(frac)?
+        } else {
+            int_part.checked_add(frac)?
+        };
+
+        Some(Ratio::new(numer, denom))
+    }
+}
+
+pub type History<T> = Vec<T>;
+
+pub fn parse<T: Number>(input: &str) -> Result<Vec<History<T>>, LineError> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            let line = line.trim();
+
+            if line.is_empty() {
+                None
+            } else {
+                Some(
+                    line.split_whitespace()
+                        .map(|s| {
+                            T::parse(s).ok_or_else(|| LineError {
+                                line: idx + 1,
+                                value: s.to_string(),
+                            })
+                        })
+                        .collect::<Result<History<T>, _>>(),
+                )
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+pub fn part1<T: Number>(histories: &[History<T>]) -> T {
+    histories
+        .iter()
+        .map(|history| find_next_num(history))
+        .fold(T::zero(), |acc, n| acc + n)
+}
+
+pub fn part2<T: Number>(histories: &[History<T>]) -> T {
+    histories
+        .iter()
+        .map(|history| find_previous_num(history))
+        .fold(T::zero(), |acc, n| acc + n)
+}
+
+/// Solves with integer values; see `Number` for the others.
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<History<i64>>;
+
+    fn parse(input: &str) -> solution::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(histories: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part1(histories))
+    }
+
+    fn part2(histories: &Self::Input<'_>) -> solution::Result<impl Into<Answer>> {
+        Ok(part2(histories))
+    }
+}
+
+pub fn find_next_num<T: Number>(history: &[T]) -> T {
+    if history.iter().all(Zero::is_zero) {
+        return T::zero();
+    }
+
+    let diff = calc_diff(history);
+    history.last().unwrap().clone() + find_next_num(&diff)
+}
+
+pub fn find_previous_num<T: Number>(history: &[T]) -> T {
+    if history.iter().all(Zero::is_zero) {
+        return T::zero();
+    }
+
+    let diff = calc_diff(history);
+    history.first().unwrap().clone() - find_previous_num(&diff)
+}
+
+pub fn calc_diff<T: Number>(history: &[T]) -> History<T> {
+    let mut diff = Vec::new();
+
+    for i in 1..history.len() {
+        diff.push(history[i].clone() - history[i - 1].clone());
+    }
+
+    diff
+}
+
+/// A row of the difference pyramid with the values extrapolated on both
+/// sides of it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Row<T> {
+    pub previous: T,
+    pub values: Vec<T>,
+    pub next: T,
+}
+
+/// Every row of differences of a history, down to the first row of zeros.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Pyramid<T> {
+    pub rows: Vec<Row<T>>,
+}
+
+impl<T: Number> Pyramid<T> {
+    pub fn build(history: &[T]) -> Pyramid<T> {
+        let mut rows = vec![history.to_vec()];
+
+        while !rows.last().unwrap().iter().all(Zero::is_zero) {
+            rows.push(calc_diff(rows.last().unwrap()));
+        }
+
+        // A history which is not polynomial ends with an empty row instead.
+        if rows.len() > 1 && rows.last().unwrap().is_empty() {
+            rows.pop();
+        }
+
+        let mut below = (T::zero(), T::zero());
+        let mut extended = rows
+            .into_iter()
+            .rev()
+            .map(|values| {
+                let previous = values.first().cloned().unwrap_or_else(T::zero) - below.0.clone();
+                let next = values.last().cloned().unwrap_or_else(T::zero) + below.1.clone();
+                below = (previous.clone(), next.clone());
+
+                Row {
+                    previous,
+                    values,
+                    next,
+                }
+            })
+            .collect::<Vec<_>>();
+        extended.reverse();
+
+        Pyramid { rows: extended }
+    }
+
+    /// Each row as its cells, with a flag telling whether it was
+    /// extrapolated.
+    pub fn cells(&self) -> Vec<Vec<(String, bool)>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                std::iter::once((row.previous.to_string(), true))
+                    .chain(row.values.iter().map(|v| (v.to_string(), false)))
+                    .chain(std::iter::once((row.next.to_string(), true)))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Lays the rows out as in the puzzle statement, each one shifted by half
+    /// a cell. Extrapolated values are bold green, or bracketed in `plain`
+    /// output.
+    pub fn to_text(&self, color: bool) -> String {
+        let cells = self
+            .cells()
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|(text, extrapolated)| match (extrapolated, color) {
+                        (true, false) => (format!("[{}]", text), extrapolated),
+                        _ => (text, extrapolated),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let width = cells
+            .iter()
+            .flatten()
+            .map(|(text, _)| text.len())
+            .max()
+            .unwrap_or(0)
+            + 2;
+        let width = width + width % 2;
+
+        cells
+            .iter()
+            .enumerate()
+            .map(|(depth, row)| {
+                let mut line = " ".repeat(depth * width / 2);
+
+                for (text, extrapolated) in row {
+                    let padded = format!("{:>width$}", text, width = width);
+
+                    if *extrapolated && color {
+                        line += &format!("\x1b[1;32m{}\x1b[0m", padded);
+                    } else {
+                        line += &padded;
+                    }
+                }
+
+                line.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn to_svg(&self) -> String {
+        const ROW_HEIGHT: usize = 30;
+
+        let cells = self.cells();
+        let longest = cells
+            .iter()
+            .flatten()
+            .map(|(text, _)| text.len())
+            .max()
+            .unwrap_or(0);
+        let cell_width = longest * 10 + 20;
+        let columns = cells.first().map_or(0, Vec::len);
+
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="monospace" font-size="16" text-anchor="middle">"#,
+            columns * cell_width,
+            cells.len() * ROW_HEIGHT + ROW_HEIGHT / 2
+        );
+        svg += "\n";
+
+        for (depth, row) in cells.iter().enumerate() {
+            for (idx, (text, extrapolated)) in row.iter().enumerate() {
+                let x = idx * cell_width + (depth + 1) * cell_width / 2;
+                let y = (depth + 1) * ROW_HEIGHT;
+                let style = if *extrapolated {
+                    r##" fill="#2a2" font-weight="bold""##
+                } else {
+                    ""
+                };
+
+                svg += &format!(
+                    "  <text x=\"{}\" y=\"{}\"{}>{}</text>\n",
+                    x,
+                    y,
+                    style,
+                    text.replace('&', "&amp;").replace('<', "&lt;")
+                );
+            }
+        }
+
+        svg += "</svg>";
+        svg
+    }
+}
+
+/// A history whose differences never reach a row of zeros, so extrapolating
+/// it would only return whatever the last difference happens to be.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NotPolynomial {
+    pub index: usize,
+    pub len: usize,
+}
+
+impl fmt::Display for NotPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "history #{} is not a polynomial within its {} values",
+            self.index + 1,
+            self.len
+        )
+    }
+}
+
+/// The degree of the polynomial generating `history`, if its differences
+/// reach a non-empty row of zeros.
+pub fn degree<T: Number>(history: &[T]) -> Option<usize> {
+    let mut row = history.to_vec();
+    let mut degree: usize = 0;
+
+    while !row.is_empty() {
+        if row.iter().all(Zero::is_zero) {
+            return Some(degree.saturating_sub(1));
+        }
+
+        row = calc_diff(&row);
+        degree += 1;
+    }
+
+    None
+}
+
+pub fn check<T: Number>(histories: &[History<T>]) -> Vec<NotPolynomial> {
+    histories
+        .iter()
+        .enumerate()
+        .filter(|(_, history)| degree(history).is_none())
+        .map(|(index, history)| NotPolynomial {
+            index,
+            len: history.len(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../../tests/fixtures/day09/example1.txt");
+
+    #[test]
+    fn test_rational() {
+        let histories = parse::<Ratio<i128>>(EXAMPLE_INPUT).unwrap();
+        assert_eq!(part1(&histories), Ratio::from_integer(114));
+
+        let histories = parse::<Ratio<i128>>("1/2 1 3/2 2\n0.25 1 2.25 4 6.25").unwrap();
+        assert_eq!(find_next_num(&histories[0]), Ratio::new(5, 2));
+        assert_eq!(find_previous_num(&histories[0]), Ratio::zero());
+        assert_eq!(find_next_num(&histories[1]), Ratio::new(9, 1));
+        assert_eq!(find_previous_num(&histories[1]), Ratio::zero());
+
+        assert_eq!(
+            parse::<Ratio<i128>>("-1.5 -0.25").unwrap()[0],
+            vec![Ratio::new(-3, 2), Ratio::new(-1, 4)]
+        );
+        assert_eq!(
+            parse::<i64>("1 2\n3 1.5"),
+            Err(LineError {
+                line: 2,
+                value: "1.5".to_string()
+            })
+        );
+        assert!(parse::<Ratio<i128>>("1/0").is_err());
+    }
+
+    #[test]
+    fn test_pyramid() {
+        let histories = parse::<i64>(EXAMPLE_INPUT).unwrap();
+        let pyramid = Pyramid::build(&histories[2]);
+
+        assert_eq!(pyramid.rows.len(), 5);
+        assert_eq!(
+            (pyramid.rows[0].previous, pyramid.rows[0].next),
+            (
+                find_previous_num(&histories[2]),
+                find_next_num(&histories[2])
+            )
+        );
+        assert_eq!(
+            pyramid.to_text(false),
+            [
+                "   [5]    10    13    16    21    30    45  [68]",
+                "      [5]     3     3     5     9    15  [23]",
+                "        [-2]     0     2     4     6   [8]",
+                "            [2]     2     2     2   [2]",
+                "               [0]     0     0   [0]",
+            ]
+            .join("\n")
+        );
+
+        let svg = pyramid.to_svg();
+        assert_eq!(svg.matches("<text").count(), 8 + 7 + 6 + 5 + 4);
+        assert_eq!(svg.matches("font-weight").count(), 10);
+        assert!(svg.contains(">68</text>"));
+    }
+
+    #[test]
+    fn test_check() {
+        let histories = parse::<i64>(EXAMPLE_INPUT).unwrap();
+        assert_eq!(
+            histories.iter().map(|h| degree(h)).collect::<Vec<_>>(),
+            vec![Some(1), Some(2), Some(3)]
+        );
+        assert!(check(&histories).is_empty());
+
+        let histories = parse::<i64>("1 2 4 8 16\n0 0 0\n5").unwrap();
+        assert_eq!(
+            check(&histories),
+            vec![
+                NotPolynomial { index: 0, len: 5 },
+                NotPolynomial { index: 2, len: 1 }
+            ]
+        );
+        assert_eq!(degree(&histories[1]), Some(0));
+    }
+}
+
+#[cfg(test)]
+mod fixtures {
+    use super::*;
+
+    fn solve(part: u8, input: &str) -> Answer {
+        solution::solve::<Day09>(part, input).unwrap()
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
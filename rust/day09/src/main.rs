@@ -1,93 +1,78 @@
+use aoc_utils::runner::{Args, CountingAlloc, Runner};
+use day09::{check, parse, part1, part2, Number, Pyramid};
+use num_rational::Ratio;
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
 fn main() {
     let input = include_str!("./input");
-    let histories = parse(input);
+    let mut args = Args::from_env();
 
-    println!("Part 1: {}", part1(&histories));
-    println!("Part 2: {}", part2(&histories));
+    if args.flag("--rational") {
+        run::<Ratio<i128>>(input, args);
+    } else {
+        run::<i64>(input, args);
+    }
 }
 
-type History = Vec<i64>;
-
-fn parse(input: &str) -> Vec<History> {
-    input
-        .lines()
-        .filter_map(|line| {
-            let line = line.trim();
-
-            if line.is_empty() {
-                None
+fn run<T: Number>(input: &str, mut args: Args) {
+    let mut runner = Runner::from_args();
+    let histories = match runner.parse(|| parse::<T>(input)) {
+        Ok(histories) => histories,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let svg = args.flag("--svg");
+    let plain = args.flag("--plain");
+    let strict = args.flag("--strict");
+    let args = args.rest();
+
+    if args.first() == Some(&"pyramid") {
+        let selected = match args.get(1) {
+            Some(n) => match n.parse::<usize>() {
+                Ok(n) if (1..=histories.len()).contains(&n) => vec![&histories[n - 1]],
+                _ => {
+                    eprintln!("error: there is no history {}", n);
+                    eprintln!(
+                        "usage: pyramid [N] [--svg | --plain], with N from 1 to {}",
+                        histories.len()
+                    );
+                    std::process::exit(1);
+                }
+            },
+            None => histories.iter().collect(),
+        };
+
+        for history in selected {
+            let pyramid = Pyramid::build(history);
+
+            if svg {
+                println!("{}", pyramid.to_svg());
             } else {
-                Some(
-                    line.split_whitespace()
-                        .map(|s| s.parse().unwrap())
-                        .collect::<History>(),
-                )
+                println!("{}", pyramid.to_text(!plain));
             }
-        })
-        .collect::<Vec<_>>()
-}
+        }
 
-fn part1(histories: &[History]) -> i64 {
-    histories
-        .iter()
-        .map(|history| find_next_num(history))
-        .sum()
-}
-
-fn part2(histories: &[History]) -> i64 {
-    histories
-        .iter()
-        .map(|history| find_previous_num(history))
-        .sum()
-}
-
-fn find_next_num(history: &[i64]) -> i64 {
-    if history.iter().all(|n| *n == 0) {
-        return 0;
+        return;
     }
 
-    let diff = calc_diff(history);
-    history.last().unwrap() + find_next_num(&diff)
-}
+    if strict {
+        let errors = check(&histories);
 
-fn find_previous_num(history: &[i64]) -> i64 {
-    if history.iter().all(|n| *n == 0) {
-        return 0;
-    }
-
-    let diff = calc_diff(history);
-    history.first().unwrap() - find_previous_num(&diff)
-}
-
-fn calc_diff(history: &[i64]) -> History {
-    let mut diff = Vec::new();
-
-    for i in 1..history.len() {
-        diff.push(history[i] - history[i - 1]);
-    }
-
-    diff
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &str = "
-        0 3 6 9 12 15
-        1 3 6 10 15 21
-        10 13 16 21 30 45
-    ";
+        if !errors.is_empty() {
+            for e in errors {
+                eprintln!("error: {}", e);
+            }
 
-    #[test]
-    fn test_part1() {
-        let histories = parse(EXAMPLE_INPUT);
-        assert_eq!(part1(&histories), 114);
+            std::process::exit(1);
+        }
     }
 
-    #[test]
-    fn test_part2() {
-        let histories = parse(EXAMPLE_INPUT);
-        assert_eq!(part2(&histories), 2);
-    }
+    runner.part(1, || part1(&histories));
+    runner.part(2, || part2(&histories));
+    runner.finish();
 }